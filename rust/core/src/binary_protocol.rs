@@ -369,9 +369,9 @@ mod tests {
         let bytes = header.to_bytes();
         let decoded = BinaryHeader::from_bytes(&bytes).unwrap();
         
-        assert_eq!(header.magic, decoded.magic);
-        assert_eq!(header.message_type, decoded.message_type);
-        assert_eq!(header.payload_length, decoded.payload_length);
+        assert_eq!({ header.magic }, { decoded.magic });
+        assert_eq!({ header.message_type }, { decoded.message_type });
+        assert_eq!({ header.payload_length }, { decoded.payload_length });
         assert!(decoded.verify_checksum(payload));
     }
     
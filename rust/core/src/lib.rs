@@ -23,7 +23,7 @@ pub mod prelude {
         transport::{Transport, DataPortalTransport},
         node::{NodeInfo, Language},
         manager::TransportManager,
-        strategy::{TransportStrategy, StrategySelector},
+        strategy::{TransportStrategy, StrategySelector, SelectionPolicy},
         error::{TransportError, Result},
     };
     pub use async_trait::async_trait;
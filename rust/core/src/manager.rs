@@ -2,8 +2,9 @@
 
 use crate::{
     Transport, DataPortalTransport, NodeInfo, TransportStrategy, TransportType, 
-    TransportError, Result, StrategySelector, StrategyPreferences
+    TransportError, Result, StrategySelector, StrategyPreferences, SelectionPolicy
 };
+use crate::binary_protocol::{BinaryMessage, MessageType};
 use async_trait::async_trait;
 use bytes::Bytes;
 use serde::{Deserialize, Serialize};
@@ -19,8 +20,15 @@ pub struct TransportManagerConfig {
     pub strategy_preferences: StrategyPreferences,
     /// Enable automatic fallback
     pub enable_fallback: bool,
-    /// Fallback timeout in milliseconds
+    /// Base deadline for each fallback send attempt in milliseconds
     pub fallback_timeout_ms: u64,
+    /// Base deadline for each primary send, and each chunk of a chunked send, in milliseconds
+    #[serde(default = "default_send_timeout_ms")]
+    pub send_timeout_ms: u64,
+    /// Send deadlines grow with payload size at this rate, so only a transport
+    /// slower than this is treated as stalled (MB/s)
+    #[serde(default = "default_min_send_throughput_mbps")]
+    pub min_send_throughput_mbps: f64,
     /// Enable transport health monitoring
     pub enable_health_monitoring: bool,
     /// Health check interval in seconds
@@ -33,12 +41,58 @@ impl Default for TransportManagerConfig {
             strategy_preferences: StrategyPreferences::default(),
             enable_fallback: true,
             fallback_timeout_ms: 5000,
+            send_timeout_ms: default_send_timeout_ms(),
+            min_send_throughput_mbps: default_min_send_throughput_mbps(),
             enable_health_monitoring: true,
             health_check_interval_seconds: 30,
         }
     }
 }
 
+fn default_send_timeout_ms() -> u64 {
+    30000
+}
+
+fn default_min_send_throughput_mbps() -> f64 {
+    1.0
+}
+
+impl TransportManagerConfig {
+    /// Validate the configuration
+    pub fn validate(&self) -> Result<()> {
+        if self.send_timeout_ms == 0 {
+            return Err(TransportError::Configuration("send_timeout_ms must be greater than zero".to_string()));
+        }
+        if self.fallback_timeout_ms == 0 {
+            return Err(TransportError::Configuration("fallback_timeout_ms must be greater than zero".to_string()));
+        }
+        if !(self.min_send_throughput_mbps.is_finite() && self.min_send_throughput_mbps > 0.0) {
+            return Err(TransportError::Configuration("min_send_throughput_mbps must be a positive number".to_string()));
+        }
+        Ok(())
+    }
+    
+    /// Replace invalid timeout settings with their defaults
+    fn sanitize_timeouts(&mut self) {
+        let defaults = Self::default();
+        if self.send_timeout_ms == 0 {
+            self.send_timeout_ms = defaults.send_timeout_ms;
+        }
+        if self.fallback_timeout_ms == 0 {
+            self.fallback_timeout_ms = defaults.fallback_timeout_ms;
+        }
+        if !(self.min_send_throughput_mbps.is_finite() && self.min_send_throughput_mbps > 0.0) {
+            self.min_send_throughput_mbps = defaults.min_send_throughput_mbps;
+        }
+    }
+    
+    /// Deadline for sending `bytes` with the given base deadline
+    fn send_deadline_ms(&self, base_ms: u64, bytes: usize) -> u64 {
+        let transfer_ms = bytes as f64 / (self.min_send_throughput_mbps * 1024.0 * 1024.0) * 1000.0;
+        base_ms.saturating_add(transfer_ms as u64)
+    }
+}
+
 /// Transport manager that coordinates multiple transport implementations
 pub struct TransportManager {
     /// Strategy selector for choosing optimal transports
@@ -81,9 +135,50 @@ impl Default for TransportHealth {
     }
 }
 
+/// Record of a transport switch that happened during a chunked send
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransportSwitch {
+    /// Transport that failed
+    pub from: TransportType,
+    /// Transport used for the remaining bytes
+    pub to: TransportType,
+    /// Byte offset at which the switch happened
+    pub offset: usize,
+}
+
+/// Result of a chunked send
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChunkedSendReport {
+    /// Total bytes delivered
+    pub bytes_sent: usize,
+    /// Number of chunks delivered
+    pub chunks_sent: usize,
+    /// Transport that delivered the final chunk
+    pub final_transport: TransportType,
+    /// Mid-stream transport switches, in order
+    pub switches: Vec<TransportSwitch>,
+}
+
+/// Failed chunked send, with the progress made before the failure
+#[derive(Debug, thiserror::Error)]
+#[error("Chunked send failed after {} bytes: {error}", .partial.bytes_sent)]
+pub struct ChunkedSendError {
+    /// Error from the last transport tried
+    pub error: TransportError,
+    /// Bytes delivered and switches made before the failure;
+    /// `final_transport` is the transport that failed
+    pub partial: ChunkedSendReport,
+}
+
 impl TransportManager {
-    /// Create a new transport manager
-    pub fn new(config: TransportManagerConfig) -> Self {
+    /// Create a new transport manager. Invalid timeout settings are replaced
+    /// with their defaults; use `try_new` to reject them instead.
+    pub fn new(mut config: TransportManagerConfig) -> Self {
+        if let Err(e) = config.validate() {
+            warn!("{}; using defaults for invalid timeout settings", e);
+            config.sanitize_timeouts();
+        }
+        
         let strategy_selector = StrategySelector::new(config.strategy_preferences.clone());
         
         Self {
@@ -94,6 +189,12 @@ impl TransportManager {
         }
     }
     
+    /// Create a new transport manager, rejecting an invalid configuration
+    pub fn try_new(config: TransportManagerConfig) -> Result<Self> {
+        config.validate()?;
+        Ok(Self::new(config))
+    }
+    
     /// Create with default configuration
     pub fn new_default() -> Self {
         Self::new(TransportManagerConfig::default())
@@ -116,9 +217,27 @@ impl TransportManager {
         selector.select_strategy(source, destination, data_size)
     }
     
-    /// Send data using the optimal transport strategy
+    /// Send data using the optimal transport strategy. The primary transport
+    /// must finish within `send_timeout_ms` plus the time to move the payload
+    /// at `min_send_throughput_mbps`, so a stalled transport cannot hang the send.
     #[instrument(skip(self, data))]
     pub async fn send_with_strategy(&self, data: &[u8], destination: &NodeInfo, strategy: &TransportStrategy) -> Result<()> {
+        let timeout_ms = self.config.send_deadline_ms(self.config.send_timeout_ms, data.len());
+        self.send_with_deadline(data, destination, strategy, timeout_ms).await
+    }
+    
+    /// Send data using the given strategy, failing with `Timeout` if the
+    /// primary transport has not completed within exactly `timeout_ms`
+    #[instrument(skip(self, data))]
+    pub async fn send_with_strategy_timeout(&self, data: &[u8], destination: &NodeInfo, strategy: &TransportStrategy, timeout_ms: u64) -> Result<()> {
+        if timeout_ms == 0 {
+            return Err(TransportError::Configuration("Send timeout must be greater than zero".to_string()));
+        }
+        self.send_with_deadline(data, destination, strategy, timeout_ms).await
+    }
+    
+    /// Send with a deadline on the primary transport
+    async fn send_with_deadline(&self, data: &[u8], destination: &NodeInfo, strategy: &TransportStrategy, timeout_ms: u64) -> Result<()> {
         let transport_type = strategy.transport_type();
         
         // Check if transport is healthy
@@ -137,7 +256,7 @@ impl TransportManager {
         let start_time = std::time::Instant::now();
         
         // Attempt to send
-        match transport.send(data, destination).await {
+        match Self::send_with_timeout(transport.as_ref(), data, destination, timeout_ms).await {
             Ok(()) => {
                 let latency = start_time.elapsed().as_secs_f64() * 1000.0;
                 let throughput = (data.len() as f64) / (1024.0 * 1024.0) / start_time.elapsed().as_secs_f64();
@@ -163,6 +282,125 @@ impl TransportManager {
         }
     }
     
+    /// Send data in chunks, switching to a fallback transport for the remaining
+    /// bytes if the current transport fails part way through. On failure the
+    /// error carries how many bytes already reached the destination.
+    #[instrument(skip(self, data))]
+    pub async fn send_chunked_with_strategy(
+        &self,
+        data: &[u8],
+        destination: &NodeInfo,
+        strategy: &TransportStrategy,
+        chunk_size: usize,
+    ) -> std::result::Result<ChunkedSendReport, ChunkedSendError> {
+        let mut report = ChunkedSendReport {
+            bytes_sent: 0,
+            chunks_sent: 0,
+            final_transport: strategy.transport_type(),
+            switches: Vec::new(),
+        };
+        
+        if chunk_size == 0 {
+            return Err(ChunkedSendError {
+                error: TransportError::Configuration("Chunk size must be greater than zero".to_string()),
+                partial: report,
+            });
+        }
+        
+        let mut failed = Vec::new();
+        
+        // Start on a healthy transport, like send_with_strategy does
+        if !self.is_transport_healthy(report.final_transport).await {
+            let unavailable = TransportError::TransportNotAvailable(report.final_transport);
+            if !self.config.enable_fallback {
+                return Err(ChunkedSendError { error: unavailable, partial: report });
+            }
+            failed.push(report.final_transport);
+            match self.next_fallback_transport(destination, &failed).await {
+                Some(next) => {
+                    debug!("Transport {:?} is unhealthy, starting chunked send on {:?}", report.final_transport, next);
+                    report.final_transport = next;
+                }
+                None => return Err(ChunkedSendError { error: unavailable, partial: report }),
+            }
+        }
+        
+        // Start of the bytes carried by the current transport
+        let mut segment_start = std::time::Instant::now();
+        let mut segment_offset = 0;
+        
+        while report.bytes_sent < data.len() {
+            let current = report.final_transport;
+            let offset = report.bytes_sent;
+            let transport = match self.transports.get(&current) {
+                Some(transport) => transport,
+                None => {
+                    return Err(ChunkedSendError {
+                        error: TransportError::TransportNotAvailable(current),
+                        partial: report,
+                    });
+                }
+            };
+            let end = std::cmp::min(offset + chunk_size, data.len());
+            
+            let timeout_ms = self.config.send_deadline_ms(self.config.send_timeout_ms, end - offset);
+            match Self::send_with_timeout(transport.as_ref(), &data[offset..end], destination, timeout_ms).await {
+                Ok(()) => {
+                    self.update_health(current, true, None).await;
+                    report.bytes_sent = end;
+                    report.chunks_sent += 1;
+                }
+                Err(e) => {
+                    // Record the failed segment so the selector learns from the switch
+                    let elapsed = segment_start.elapsed().as_secs_f64();
+                    let throughput = ((offset - segment_offset) as f64) / (1024.0 * 1024.0) / elapsed;
+                    self.update_performance(&destination.id, current, elapsed * 1000.0, throughput, false).await;
+                    self.update_health(current, false, Some(e.to_string())).await;
+                    failed.push(current);
+                    
+                    if !self.config.enable_fallback {
+                        return Err(ChunkedSendError { error: e, partial: report });
+                    }
+                    
+                    let next = match self.next_fallback_transport(destination, &failed).await {
+                        Some(next) => next,
+                        None => return Err(ChunkedSendError { error: e, partial: report }),
+                    };
+                    
+                    warn!("Transport {:?} failed at offset {}, continuing with {:?}: {}", current, offset, next, e);
+                    report.switches.push(TransportSwitch { from: current, to: next, offset });
+                    report.final_transport = next;
+                    segment_start = std::time::Instant::now();
+                    segment_offset = offset;
+                }
+            }
+        }
+        
+        // Charge the final transport only for the bytes it carried
+        let elapsed = segment_start.elapsed().as_secs_f64();
+        let throughput = ((data.len() - segment_offset) as f64) / (1024.0 * 1024.0) / elapsed;
+        self.update_performance(&destination.id, report.final_transport, elapsed * 1000.0, throughput, true).await;
+        
+        debug!("Sent {} bytes in {} chunks, final transport {:?}", data.len(), report.chunks_sent, report.final_transport);
+        Ok(report)
+    }
+    
+    /// Pick the next healthy registered transport that has not failed yet
+    async fn next_fallback_transport(&self, destination: &NodeInfo, failed: &[TransportType]) -> Option<TransportType> {
+        let recommended_transports = self.strategy_selector.read().await.get_recommended_transports(destination);
+        
+        for transport_type in recommended_transports {
+            if failed.contains(&transport_type) || !self.transports.contains_key(&transport_type) {
+                continue;
+            }
+            if self.is_transport_healthy(transport_type).await {
+                return Some(transport_type);
+            }
+        }
+        
+        None
+    }
+    
     /// Send data with automatic fallback
     async fn send_with_fallback(&self, data: &[u8], destination: &NodeInfo) -> Result<()> {
         let selector = self.strategy_selector.read().await;
@@ -171,7 +409,8 @@ impl TransportManager {
         for transport_type in recommended_transports {
             if let Some(transport) = self.transports.get(&transport_type) {
                 if self.is_transport_healthy(transport_type).await {
-                    match transport.send(data, destination).await {
+                    let timeout_ms = self.config.send_deadline_ms(self.config.fallback_timeout_ms, data.len());
+                    match Self::send_with_timeout(transport.as_ref(), data, destination, timeout_ms).await {
                         Ok(()) => {
                             debug!("Fallback successful using {:?}", transport_type);
                             return Ok(());
@@ -209,7 +448,7 @@ impl TransportManager {
         let start_time = std::time::Instant::now();
         
         // Attempt to receive
-        match transport.receive(source, timeout_ms).await {
+        match Self::receive_with_timeout(transport.as_ref(), source, timeout_ms).await {
             Ok(data) => {
                 let latency = start_time.elapsed().as_secs_f64() * 1000.0;
                 let throughput = (data.len() as f64) / (1024.0 * 1024.0) / start_time.elapsed().as_secs_f64();
@@ -243,7 +482,7 @@ impl TransportManager {
         for transport_type in recommended_transports {
             if let Some(transport) = self.transports.get(&transport_type) {
                 if self.is_transport_healthy(transport_type).await {
-                    match transport.receive(source, timeout_ms).await {
+                    match Self::receive_with_timeout(transport.as_ref(), source, timeout_ms).await {
                         Ok(data) => {
                             debug!("Fallback receive successful using {:?}", transport_type);
                             return Ok(data);
@@ -260,6 +499,20 @@ impl TransportManager {
         Err(TransportError::Internal("All transport fallbacks failed".to_string()))
     }
     
+    /// Send through a transport, giving up after `timeout_ms`
+    async fn send_with_timeout(transport: &dyn Transport, data: &[u8], destination: &NodeInfo, timeout_ms: u64) -> Result<()> {
+        tokio::time::timeout(std::time::Duration::from_millis(timeout_ms), transport.send(data, destination))
+            .await
+            .map_err(|_| TransportError::Timeout { timeout_ms })?
+    }
+    
+    /// Receive through a transport, enforcing the timeout even if the transport ignores it
+    async fn receive_with_timeout(transport: &dyn Transport, source: &NodeInfo, timeout_ms: u64) -> Result<Bytes> {
+        tokio::time::timeout(std::time::Duration::from_millis(timeout_ms), transport.receive(source, timeout_ms))
+            .await
+            .map_err(|_| TransportError::Timeout { timeout_ms })?
+    }
+    
     /// Check if a transport is healthy
     async fn is_transport_healthy(&self, transport_type: TransportType) -> bool {
         let health = self.transport_health.read().await;
@@ -297,6 +550,49 @@ impl TransportManager {
         selector.update_performance(node_id, transport_type, latency_ms, throughput_mbps, success);
     }
     
+    /// Register a custom transport selection policy
+    pub async fn set_selection_policy(&self, policy: Box<dyn SelectionPolicy>) {
+        let mut selector = self.strategy_selector.write().await;
+        selector.set_policy(policy);
+    }
+    
+    /// Record a measured round-trip time to a node
+    pub async fn record_rtt(&self, node_id: &str, transport_type: TransportType, rtt: std::time::Duration) {
+        let mut selector = self.strategy_selector.write().await;
+        selector.record_rtt(node_id, transport_type, rtt);
+    }
+    
+    /// Probe each healthy registered transport with a small heartbeat send,
+    /// recording the measured latency for RTT-aware selection
+    pub async fn probe_rtt(&self, destination: &NodeInfo) -> Result<HashMap<TransportType, std::time::Duration>> {
+        let probe = BinaryMessage::new(MessageType::Heartbeat, Bytes::new())
+            .map_err(|e| TransportError::Internal(e.to_string()))?
+            .to_bytes();
+        let mut measured = HashMap::new();
+        
+        for (transport_type, transport) in &self.transports {
+            if !self.is_transport_healthy(*transport_type).await || !transport.can_communicate_with(destination).await {
+                continue;
+            }
+            
+            let start_time = std::time::Instant::now();
+            match Self::send_with_timeout(transport.as_ref(), &probe, destination, self.config.send_timeout_ms).await {
+                Ok(()) => {
+                    let rtt = start_time.elapsed();
+                    debug!("Probed {:?} to {}: {:.2}ms", transport_type, destination.id, rtt.as_secs_f64() * 1000.0);
+                    self.record_rtt(&destination.id, *transport_type, rtt).await;
+                    measured.insert(*transport_type, rtt);
+                }
+                Err(e) => {
+                    warn!("RTT probe over {:?} failed: {}", transport_type, e);
+                    self.update_health(*transport_type, false, Some(e.to_string())).await;
+                }
+            }
+        }
+        
+        Ok(measured)
+    }
+    
     /// Get available transports
     pub async fn get_available_transports(&self) -> Vec<crate::TransportInfo> {
         let mut transports = Vec::new();
@@ -491,6 +787,140 @@ mod tests {
         }
     }
 
+    // Mock transport that records delivered bytes and fails after a number of sends
+    struct RecordingTransport {
+        transport_type: TransportType,
+        fail_after: Option<usize>,
+        sends: std::sync::atomic::AtomicUsize,
+        received: std::sync::Mutex<Vec<u8>>,
+    }
+    
+    impl RecordingTransport {
+        fn new(transport_type: TransportType, fail_after: Option<usize>) -> Self {
+            Self {
+                transport_type,
+                fail_after,
+                sends: std::sync::atomic::AtomicUsize::new(0),
+                received: std::sync::Mutex::new(Vec::new()),
+            }
+        }
+    }
+    
+    #[async_trait]
+    impl Transport for RecordingTransport {
+        async fn send(&self, data: &[u8], _destination: &NodeInfo) -> Result<()> {
+            let sends = self.sends.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if self.fail_after.is_some_and(|limit| sends >= limit) {
+                return Err(TransportError::SharedMemory("Mock segment failure".to_string()));
+            }
+            self.received.lock().unwrap().extend_from_slice(data);
+            Ok(())
+        }
+        
+        async fn receive(&self, _source: &NodeInfo, _timeout_ms: u64) -> Result<Bytes> {
+            Ok(Bytes::new())
+        }
+        
+        async fn can_communicate_with(&self, _node: &NodeInfo) -> bool {
+            true
+        }
+        
+        fn transport_type(&self) -> TransportType {
+            self.transport_type
+        }
+        
+        async fn get_metrics(&self) -> crate::TransportMetrics {
+            crate::TransportMetrics {
+                transport_type: self.transport_type,
+                messages_sent: 0,
+                messages_received: 0,
+                bytes_sent: 0,
+                bytes_received: 0,
+                average_latency_ms: 0.0,
+                average_throughput_mbps: 0.0,
+                error_count: 0,
+                last_error: None,
+            }
+        }
+    }
+    
+    // Mock transport that never completes, like a half-open connection
+    struct StallingTransport;
+    
+    #[async_trait]
+    impl Transport for StallingTransport {
+        async fn send(&self, _data: &[u8], _destination: &NodeInfo) -> Result<()> {
+            std::future::pending().await
+        }
+        
+        async fn receive(&self, _source: &NodeInfo, _timeout_ms: u64) -> Result<Bytes> {
+            std::future::pending().await
+        }
+        
+        async fn can_communicate_with(&self, _node: &NodeInfo) -> bool {
+            true
+        }
+        
+        fn transport_type(&self) -> TransportType {
+            TransportType::RustNetwork
+        }
+        
+        async fn get_metrics(&self) -> crate::TransportMetrics {
+            crate::TransportMetrics {
+                transport_type: TransportType::RustNetwork,
+                messages_sent: 0,
+                messages_received: 0,
+                bytes_sent: 0,
+                bytes_received: 0,
+                average_latency_ms: 0.0,
+                average_throughput_mbps: 0.0,
+                error_count: 0,
+                last_error: None,
+            }
+        }
+    }
+    
+    // Mock transport whose sends take time proportional to the payload size
+    struct SlowTransport {
+        transport_type: TransportType,
+        ms_per_mib: u64,
+    }
+    
+    #[async_trait]
+    impl Transport for SlowTransport {
+        async fn send(&self, data: &[u8], _destination: &NodeInfo) -> Result<()> {
+            let delay_ms = data.len() as u64 * self.ms_per_mib / (1024 * 1024);
+            tokio::time::sleep(std::time::Duration::from_millis(delay_ms)).await;
+            Ok(())
+        }
+        
+        async fn receive(&self, _source: &NodeInfo, _timeout_ms: u64) -> Result<Bytes> {
+            Ok(Bytes::new())
+        }
+        
+        async fn can_communicate_with(&self, _node: &NodeInfo) -> bool {
+            true
+        }
+        
+        fn transport_type(&self) -> TransportType {
+            self.transport_type
+        }
+        
+        async fn get_metrics(&self) -> crate::TransportMetrics {
+            crate::TransportMetrics {
+                transport_type: self.transport_type,
+                messages_sent: 0,
+                messages_received: 0,
+                bytes_sent: 0,
+                bytes_received: 0,
+                average_latency_ms: 0.0,
+                average_throughput_mbps: 0.0,
+                error_count: 0,
+                last_error: None,
+            }
+        }
+    }
+    
     #[tokio::test]
     async fn test_transport_manager_creation() {
         let manager = TransportManager::new_default();
@@ -557,4 +987,269 @@ mod tests {
         assert!(!shared_mem_health.is_healthy);
         assert_eq!(shared_mem_health.consecutive_failures, 3);
     }
-}
\ No newline at end of file
+    
+    #[tokio::test]
+    async fn test_chunked_send_falls_back_mid_stream() {
+        let mut manager = TransportManager::new_default();
+        let shared_memory = Arc::new(RecordingTransport::new(TransportType::SharedMemory, Some(4)));
+        let network = Arc::new(RecordingTransport::new(TransportType::RustNetwork, None));
+        
+        manager.register_transport(TransportType::SharedMemory, shared_memory.clone()).await;
+        manager.register_transport(TransportType::RustNetwork, network.clone()).await;
+        
+        let mut destination = NodeInfo::new("peer", Language::Rust);
+        destination.endpoint = Some("127.0.0.1:9000".to_string());
+        let strategy = TransportStrategy::SharedMemory {
+            region_name: "test_region".to_string(),
+        };
+        
+        let data: Vec<u8> = (0..8192u32).map(|i| (i % 251) as u8).collect();
+        let report = manager.send_chunked_with_strategy(&data, &destination, &strategy, 1024).await.unwrap();
+        
+        assert_eq!(report.bytes_sent, data.len());
+        assert_eq!(report.chunks_sent, 8);
+        assert_eq!(report.final_transport, TransportType::RustNetwork);
+        assert_eq!(report.switches, vec![TransportSwitch {
+            from: TransportType::SharedMemory,
+            to: TransportType::RustNetwork,
+            offset: 4096,
+        }]);
+        
+        let mut delivered = shared_memory.received.lock().unwrap().clone();
+        delivered.extend_from_slice(&network.received.lock().unwrap());
+        assert_eq!(delivered, data);
+    }
+
+    #[tokio::test]
+    async fn test_chunked_send_times_only_final_segment() {
+        let mut manager = TransportManager::new(TransportManagerConfig {
+            send_timeout_ms: 200,
+            ..Default::default()
+        });
+        manager.register_transport(TransportType::SharedMemory, Arc::new(MockTransport {
+            transport_type: TransportType::SharedMemory,
+            should_fail: false,
+        })).await;
+        manager.register_transport(TransportType::RustNetwork, Arc::new(StallingTransport)).await;
+        manager.register_transport(TransportType::DataPortal, Arc::new(RecordingTransport::new(TransportType::DataPortal, None))).await;
+        
+        let destination = NodeInfo::remote("peer", Language::Rust, "10.0.0.2:8080");
+        let strategy = TransportStrategy::RustNetwork {
+            endpoint: "10.0.0.2:8080".to_string(),
+        };
+        
+        let report = manager.send_chunked_with_strategy(&[7u8; 4096], &destination, &strategy, 1024).await.unwrap();
+        assert_eq!(report.final_transport, TransportType::DataPortal);
+        
+        // The 200ms stall on the failed transport is not charged to the fallback
+        let selector = manager.strategy_selector.read().await;
+        let history = selector.get_performance_history("peer").unwrap();
+        assert!(history.metrics[&TransportType::DataPortal].avg_latency_ms < 200.0);
+        
+        // The switch is recorded as a failure for the transport that stalled
+        assert_eq!(history.metrics[&TransportType::RustNetwork].success_rate, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_chunked_send_reports_partial_progress() {
+        let mut manager = TransportManager::new_default();
+        let shared_memory = Arc::new(RecordingTransport::new(TransportType::SharedMemory, Some(2)));
+        let network = Arc::new(RecordingTransport::new(TransportType::RustNetwork, Some(3)));
+        manager.register_transport(TransportType::SharedMemory, shared_memory.clone()).await;
+        manager.register_transport(TransportType::RustNetwork, network.clone()).await;
+        
+        let mut destination = NodeInfo::new("peer", Language::Rust);
+        destination.endpoint = Some("127.0.0.1:9000".to_string());
+        let strategy = TransportStrategy::SharedMemory {
+            region_name: "test_region".to_string(),
+        };
+        
+        let data = vec![1u8; 8192];
+        let err = manager.send_chunked_with_strategy(&data, &destination, &strategy, 1024).await.unwrap_err();
+        
+        // Two chunks over shared memory, three over the network, then nothing left to try
+        assert!(matches!(err.error, TransportError::SharedMemory(_)));
+        assert_eq!(err.partial.bytes_sent, 5 * 1024);
+        assert_eq!(err.partial.chunks_sent, 5);
+        assert_eq!(err.partial.final_transport, TransportType::RustNetwork);
+        assert_eq!(err.partial.switches, vec![TransportSwitch {
+            from: TransportType::SharedMemory,
+            to: TransportType::RustNetwork,
+            offset: 2048,
+        }]);
+        assert_eq!(shared_memory.received.lock().unwrap().len() + network.received.lock().unwrap().len(), 5 * 1024);
+    }
+
+    #[tokio::test]
+    async fn test_chunked_send_skips_unhealthy_initial_transport() {
+        let mut manager = TransportManager::new_default();
+        let shared_memory = Arc::new(RecordingTransport::new(TransportType::SharedMemory, None));
+        let network = Arc::new(RecordingTransport::new(TransportType::RustNetwork, None));
+        manager.register_transport(TransportType::SharedMemory, shared_memory.clone()).await;
+        manager.register_transport(TransportType::RustNetwork, network.clone()).await;
+        for _ in 0..3 {
+            manager.update_health(TransportType::SharedMemory, false, None).await;
+        }
+        
+        let mut destination = NodeInfo::new("peer", Language::Rust);
+        destination.endpoint = Some("127.0.0.1:9000".to_string());
+        let strategy = TransportStrategy::SharedMemory {
+            region_name: "test_region".to_string(),
+        };
+        
+        let report = manager.send_chunked_with_strategy(&[3u8; 2048], &destination, &strategy, 1024).await.unwrap();
+        assert_eq!(report.final_transport, TransportType::RustNetwork);
+        assert!(report.switches.is_empty());
+        assert!(shared_memory.received.lock().unwrap().is_empty());
+        assert_eq!(network.received.lock().unwrap().len(), 2048);
+    }
+
+    #[tokio::test]
+    async fn test_stalled_transport_times_out() {
+        let mut manager = TransportManager::try_new(TransportManagerConfig {
+            enable_fallback: false,
+            send_timeout_ms: 40,
+            ..Default::default()
+        }).unwrap();
+        manager.register_transport(TransportType::RustNetwork, Arc::new(StallingTransport)).await;
+        
+        let source = NodeInfo::remote("stalled", Language::Rust, "10.0.0.2:8080");
+        let strategy = TransportStrategy::RustNetwork {
+            endpoint: "10.0.0.2:8080".to_string(),
+        };
+        
+        // The default path is bounded too
+        let result = manager.send_with_strategy(b"test data", &source, &strategy).await;
+        assert!(matches!(result, Err(TransportError::Timeout { timeout_ms: 40 })));
+        
+        let result = manager.send_with_strategy_timeout(b"test data", &source, &strategy, 50).await;
+        assert!(matches!(result, Err(TransportError::Timeout { timeout_ms: 50 })));
+        
+        let result = manager.send_chunked_with_strategy(b"test data", &source, &strategy, 4).await;
+        assert!(matches!(result, Err(ChunkedSendError { error: TransportError::Timeout { timeout_ms: 40 }, .. })));
+        
+        let health = manager.get_transport_health().await;
+        assert_eq!(health[&TransportType::RustNetwork].consecutive_failures, 3);
+        assert!(!health[&TransportType::RustNetwork].is_healthy);
+        
+        // Re-registering resets the health so the receive path is exercised
+        manager.register_transport(TransportType::RustNetwork, Arc::new(StallingTransport)).await;
+        let result = manager.receive_with_strategy(&source, &strategy, 50).await;
+        assert!(matches!(result, Err(TransportError::Timeout { timeout_ms: 50 })));
+        
+        let result = manager.send_with_strategy_timeout(b"test data", &source, &strategy, 0).await;
+        assert!(matches!(result, Err(TransportError::Configuration(_))));
+    }
+
+    #[tokio::test]
+    async fn test_fallback_uses_fallback_timeout() {
+        let mut manager = TransportManager::new(TransportManagerConfig {
+            fallback_timeout_ms: 30,
+            ..Default::default()
+        });
+        manager.register_transport(TransportType::RustNetwork, Arc::new(MockTransport {
+            transport_type: TransportType::RustNetwork,
+            should_fail: true,
+        })).await;
+        manager.register_transport(TransportType::DataPortal, Arc::new(StallingTransport)).await;
+        
+        let destination = NodeInfo::remote("remote", Language::Rust, "10.0.0.2:8080");
+        let strategy = TransportStrategy::RustNetwork {
+            endpoint: "10.0.0.2:8080".to_string(),
+        };
+        
+        // The stalled fallback is abandoned after fallback_timeout_ms rather than hanging
+        let result = manager.send_with_strategy(b"test data", &destination, &strategy).await;
+        assert!(matches!(result, Err(TransportError::Internal(_))));
+        let health = manager.get_transport_health().await;
+        assert!(health[&TransportType::DataPortal].last_error.as_deref().unwrap().contains("30ms"));
+    }
+
+    #[tokio::test]
+    async fn test_send_deadline_scales_with_payload() {
+        let mut manager = TransportManager::new(TransportManagerConfig {
+            send_timeout_ms: 20,
+            fallback_timeout_ms: 20,
+            min_send_throughput_mbps: 10.0,
+            ..Default::default()
+        });
+        manager.register_transport(TransportType::RustNetwork, Arc::new(SlowTransport {
+            transport_type: TransportType::RustNetwork,
+            ms_per_mib: 50,
+        })).await;
+        manager.register_transport(TransportType::DataPortal, Arc::new(SlowTransport {
+            transport_type: TransportType::DataPortal,
+            ms_per_mib: 50,
+        })).await;
+        
+        let destination = NodeInfo::remote("remote", Language::Rust, "10.0.0.2:8080");
+        let data = vec![0u8; 2 * 1024 * 1024];
+        
+        // 100ms for 2MiB exceeds the 20ms base but is well above 10 MB/s
+        let strategy = TransportStrategy::RustNetwork {
+            endpoint: "10.0.0.2:8080".to_string(),
+        };
+        manager.send_with_strategy(&data, &destination, &strategy).await.unwrap();
+        
+        // Fallback attempts get the same size-scaled allowance
+        for _ in 0..3 {
+            manager.update_health(TransportType::RustNetwork, false, None).await;
+        }
+        manager.send_with_strategy(&data, &destination, &strategy).await.unwrap();
+        let health = manager.get_transport_health().await;
+        assert_eq!(health[&TransportType::DataPortal].consecutive_failures, 0);
+    }
+
+    #[tokio::test]
+    async fn test_probe_rtt_feeds_selection() {
+        let mut manager = TransportManager::new(TransportManagerConfig {
+            send_timeout_ms: 30,
+            ..Default::default()
+        });
+        manager.register_transport(TransportType::RustNetwork, Arc::new(StallingTransport)).await;
+        manager.register_transport(TransportType::DataPortal, Arc::new(MockTransport {
+            transport_type: TransportType::DataPortal,
+            should_fail: false,
+        })).await;
+        
+        let source = NodeInfo::new("source", Language::Rust);
+        let destination = NodeInfo::remote("remote", Language::Rust, "10.0.0.2:8080");
+        
+        // Only the transport that answered is measured
+        let measured = manager.probe_rtt(&destination).await.unwrap();
+        assert_eq!(measured.keys().copied().collect::<Vec<_>>(), vec![TransportType::DataPortal]);
+        
+        // Without the probe the language default (RustNetwork) would be chosen
+        let strategy = manager.get_strategy(&source, &destination, 4096).await.unwrap();
+        assert_eq!(strategy.transport_type(), TransportType::DataPortal);
+    }
+
+    #[test]
+    fn test_config_rejects_zero_timeouts() {
+        let config = TransportManagerConfig {
+            send_timeout_ms: 0,
+            ..Default::default()
+        };
+        assert!(matches!(config.validate(), Err(TransportError::Configuration(_))));
+        assert!(TransportManager::try_new(config).is_err());
+        
+        let config = TransportManagerConfig {
+            fallback_timeout_ms: 0,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+        assert!(TransportManagerConfig::default().validate().is_ok());
+        
+        // new() cannot fail, so it falls back to the defaults instead
+        let manager = TransportManager::new(TransportManagerConfig {
+            send_timeout_ms: 0,
+            fallback_timeout_ms: 0,
+            min_send_throughput_mbps: 0.0,
+            ..Default::default()
+        });
+        let defaults = TransportManagerConfig::default();
+        assert_eq!(manager.config.send_timeout_ms, defaults.send_timeout_ms);
+        assert_eq!(manager.config.fallback_timeout_ms, defaults.fallback_timeout_ms);
+        assert_eq!(manager.config.min_send_throughput_mbps, defaults.min_send_throughput_mbps);
+    }
+}
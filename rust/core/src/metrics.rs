@@ -2,7 +2,7 @@
 
 use crate::{TransportType, NodeInfo};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::{Arc, atomic::{AtomicU64, AtomicU32, Ordering}};
 use std::time::{SystemTime, Duration, Instant};
 use tokio::sync::RwLock;
@@ -13,10 +13,41 @@ pub struct MetricsCollector {
     transport_metrics: Arc<RwLock<HashMap<TransportType, TransportMetricsState>>>,
     /// Node-specific metrics
     node_metrics: Arc<RwLock<HashMap<String, NodeMetricsState>>>,
+    /// Metrics per application label (key, value)
+    label_metrics: Arc<RwLock<HashMap<(String, String), LabelMetricsSummary>>>,
     /// System-wide counters
     global_counters: GlobalCounters,
     /// Start time for uptime calculation
     start_time: SystemTime,
+    /// Collector configuration
+    config: MetricsConfig,
+}
+
+/// Metrics collector configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricsConfig {
+    /// Maximum number of recent samples kept per transport (at least 1)
+    pub sample_window_size: usize,
+    /// Ignore samples older than this when computing averages
+    pub sample_max_age: Option<Duration>,
+    /// Maximum number of distinct label (key, value) pairs tracked; updates
+    /// for new pairs beyond this are dropped and counted instead
+    #[serde(default = "default_max_label_pairs")]
+    pub max_label_pairs: usize,
+}
+
+fn default_max_label_pairs() -> usize {
+    1000
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            sample_window_size: 100,
+            sample_max_age: None,
+            max_label_pairs: default_max_label_pairs(),
+        }
+    }
 }
 
 /// Global system counters
@@ -31,6 +62,8 @@ struct GlobalCounters {
     total_bytes_received: AtomicU64,
     /// Total errors across all transports
     total_errors: AtomicU64,
+    /// Label pairs dropped because `max_label_pairs` was reached
+    dropped_label_pairs: AtomicU64,
 }
 
 /// Transport-specific metrics state
@@ -45,10 +78,10 @@ struct TransportMetricsState {
     bytes_received: AtomicU64,
     /// Error counter
     error_count: AtomicU64,
-    /// Latency samples (for calculating average)
-    latency_samples: Arc<RwLock<Vec<f64>>>,
-    /// Throughput samples (for calculating average)
-    throughput_samples: Arc<RwLock<Vec<f64>>>,
+    /// Recent latency samples (for calculating average)
+    latency_samples: Arc<RwLock<VecDeque<(Instant, f64)>>>,
+    /// Recent throughput samples (for calculating average)
+    throughput_samples: Arc<RwLock<VecDeque<(Instant, f64)>>>,
     /// Last error message
     last_error: Arc<RwLock<Option<String>>>,
     /// Last operation timestamp
@@ -85,6 +118,7 @@ impl Default for GlobalCounters {
             total_bytes_sent: AtomicU64::new(0),
             total_bytes_received: AtomicU64::new(0),
             total_errors: AtomicU64::new(0),
+            dropped_label_pairs: AtomicU64::new(0),
         }
     }
 }
@@ -97,8 +131,8 @@ impl Default for TransportMetricsState {
             bytes_sent: AtomicU64::new(0),
             bytes_received: AtomicU64::new(0),
             error_count: AtomicU64::new(0),
-            latency_samples: Arc::new(RwLock::new(Vec::new())),
-            throughput_samples: Arc::new(RwLock::new(Vec::new())),
+            latency_samples: Arc::new(RwLock::new(VecDeque::new())),
+            throughput_samples: Arc::new(RwLock::new(VecDeque::new())),
             last_error: Arc::new(RwLock::new(None)),
             last_operation: Arc::new(RwLock::new(None)),
         }
@@ -108,11 +142,21 @@ impl Default for TransportMetricsState {
 impl MetricsCollector {
     /// Create a new metrics collector
     pub fn new() -> Self {
+        Self::with_config(MetricsConfig::default())
+    }
+    
+    /// Create a metrics collector with the given configuration.
+    /// A `sample_window_size` of 0 is raised to 1 so averages stay meaningful.
+    pub fn with_config(mut config: MetricsConfig) -> Self {
+        config.sample_window_size = config.sample_window_size.max(1);
+        
         Self {
             transport_metrics: Arc::new(RwLock::new(HashMap::new())),
             node_metrics: Arc::new(RwLock::new(HashMap::new())),
+            label_metrics: Arc::new(RwLock::new(HashMap::new())),
             global_counters: GlobalCounters::default(),
             start_time: SystemTime::now(),
+            config,
         }
     }
     
@@ -166,6 +210,72 @@ impl MetricsCollector {
         self.update_node_metrics(&source.id, source, bytes, latency_ms, success, transport_type, false).await;
     }
     
+    /// Record a send operation tagged with application labels
+    /// (e.g. `job=backup-2024-06`) so it can be attributed and filtered later
+    pub async fn record_send_labeled(
+        &self,
+        transport_type: TransportType,
+        destination: &NodeInfo,
+        bytes: usize,
+        latency_ms: f64,
+        success: bool,
+        labels: &HashMap<String, String>,
+    ) {
+        self.record_send(transport_type, destination, bytes, latency_ms, success, None).await;
+        self.update_label_metrics(labels, bytes, success, true).await;
+    }
+    
+    /// Record a receive operation tagged with application labels
+    pub async fn record_receive_labeled(
+        &self,
+        transport_type: TransportType,
+        source: &NodeInfo,
+        bytes: usize,
+        latency_ms: f64,
+        success: bool,
+        labels: &HashMap<String, String>,
+    ) {
+        self.record_receive(transport_type, source, bytes, latency_ms, success, None).await;
+        self.update_label_metrics(labels, bytes, success, false).await;
+    }
+    
+    /// Update per-label metrics
+    async fn update_label_metrics(&self, labels: &HashMap<String, String>, bytes: usize, success: bool, is_send: bool) {
+        let mut metrics = self.label_metrics.write().await;
+        
+        for (key, value) in labels {
+            let pair = (key.clone(), value.clone());
+            if !metrics.contains_key(&pair) && metrics.len() >= self.config.max_label_pairs {
+                self.global_counters.dropped_label_pairs.fetch_add(1, Ordering::SeqCst);
+                continue;
+            }
+            
+            let summary = metrics.entry(pair).or_insert_with(|| {
+                LabelMetricsSummary {
+                    key: key.clone(),
+                    value: value.clone(),
+                    ..Default::default()
+                }
+            });
+            
+            if is_send {
+                summary.messages_sent += 1;
+                if success {
+                    summary.bytes_sent += bytes as u64;
+                }
+            } else {
+                summary.messages_received += 1;
+                if success {
+                    summary.bytes_received += bytes as u64;
+                }
+            }
+            
+            if !success {
+                summary.error_count += 1;
+            }
+        }
+    }
+    
     /// Update transport-specific metrics
     async fn update_transport_metrics(
         &self,
@@ -192,19 +302,21 @@ impl MetricsCollector {
         }
         
         if success {
-            // Add latency sample (keep only last 100 samples)
+            // Add latency sample (keep only the configured window)
+            let now = Instant::now();
+            let window_size = self.config.sample_window_size;
             let mut latency_samples = state.latency_samples.write().await;
-            latency_samples.push(latency_ms);
-            if latency_samples.len() > 100 {
-                latency_samples.remove(0);
+            latency_samples.push_back((now, latency_ms));
+            while latency_samples.len() > window_size {
+                latency_samples.pop_front();
             }
             
             // Calculate and add throughput sample
             let throughput_mbps = (bytes as f64) / (1024.0 * 1024.0) / (latency_ms / 1000.0);
             let mut throughput_samples = state.throughput_samples.write().await;
-            throughput_samples.push(throughput_mbps);
-            if throughput_samples.len() > 100 {
-                throughput_samples.remove(0);
+            throughput_samples.push_back((now, throughput_mbps));
+            while throughput_samples.len() > window_size {
+                throughput_samples.pop_front();
             }
             
             // Update last operation time
@@ -277,8 +389,8 @@ impl MetricsCollector {
     pub async fn get_transport_metrics(&self, transport_type: TransportType) -> Option<TransportMetricsSummary> {
         let metrics = self.transport_metrics.read().await;
         if let Some(state) = metrics.get(&transport_type) {
-            let latency_samples = state.latency_samples.read().await;
-            let throughput_samples = state.throughput_samples.read().await;
+            let latency_samples = self.recent_samples(&*state.latency_samples.read().await);
+            let throughput_samples = self.recent_samples(&*state.throughput_samples.read().await);
             
             let average_latency = if latency_samples.is_empty() {
                 0.0
@@ -292,6 +404,8 @@ impl MetricsCollector {
                 throughput_samples.iter().sum::<f64>() / throughput_samples.len() as f64
             };
             
+            let max_throughput = throughput_samples.iter().cloned().fold(0.0, f64::max);
+            
             let last_error = state.last_error.read().await.clone();
             let last_operation = *state.last_operation.read().await;
             
@@ -303,6 +417,7 @@ impl MetricsCollector {
                 bytes_received: state.bytes_received.load(Ordering::SeqCst),
                 average_latency_ms: average_latency,
                 average_throughput_mbps: average_throughput,
+                max_throughput_mbps: max_throughput,
                 error_count: state.error_count.load(Ordering::SeqCst),
                 last_error,
                 last_operation,
@@ -381,17 +496,98 @@ impl MetricsCollector {
         summaries
     }
     
-    /// Clear all metrics
-    pub async fn clear_metrics(&self) {
-        self.transport_metrics.write().await.clear();
-        self.node_metrics.write().await.clear();
+    /// Get metrics for operations tagged with a label
+    pub async fn get_label_metrics(&self, key: &str, value: &str) -> Option<LabelMetricsSummary> {
+        self.label_metrics.read().await
+            .get(&(key.to_string(), value.to_string()))
+            .cloned()
+    }
+    
+    /// Get metrics for every recorded label, sorted by key and value
+    pub async fn get_all_label_metrics(&self) -> Vec<LabelMetricsSummary> {
+        let mut summaries: Vec<_> = self.label_metrics.read().await.values().cloned().collect();
+        summaries.sort_by(|a, b| (&a.key, &a.value).cmp(&(&b.key, &b.value)));
+        summaries
+    }
+    
+    /// Number of label updates dropped because `max_label_pairs` was reached
+    pub fn dropped_label_pairs(&self) -> u64 {
+        self.global_counters.dropped_label_pairs.load(Ordering::SeqCst)
+    }
+    
+    /// Get throughput split between shared memory and network transports
+    pub async fn get_throughput_by_mode(&self) -> ThroughputByMode {
+        let mut shared_memory = ModeThroughputAccumulator::default();
+        let mut network = ModeThroughputAccumulator::default();
+        let metrics = self.transport_metrics.read().await;
         
-        // Reset global counters
+        for (transport_type, state) in metrics.iter() {
+            let accumulator = match transport_type {
+                TransportType::SharedMemory => &mut shared_memory,
+                TransportType::SwiftNetwork | TransportType::RustNetwork | TransportType::DataPortal => &mut network,
+            };
+            
+            accumulator.bytes += state.bytes_sent.load(Ordering::SeqCst) + state.bytes_received.load(Ordering::SeqCst);
+            accumulator.samples.extend(self.recent_samples(&*state.throughput_samples.read().await));
+        }
+        
+        ThroughputByMode {
+            shared_memory: shared_memory.finish(),
+            network: network.finish(),
+        }
+    }
+    
+    /// Values of the samples that fall within the configured maximum age
+    fn recent_samples(&self, samples: &VecDeque<(Instant, f64)>) -> Vec<f64> {
+        samples.iter()
+            .filter(|(recorded_at, _)| {
+                self.config.sample_max_age
+                    .is_none_or(|max_age| recorded_at.elapsed() <= max_age)
+            })
+            .map(|(_, value)| *value)
+            .collect()
+    }
+    
+    /// Reset lifetime counters while keeping the recent sample window
+    pub async fn reset_lifetime_counters(&self) {
+        for state in self.transport_metrics.read().await.values() {
+            state.messages_sent.store(0, Ordering::SeqCst);
+            state.messages_received.store(0, Ordering::SeqCst);
+            state.bytes_sent.store(0, Ordering::SeqCst);
+            state.bytes_received.store(0, Ordering::SeqCst);
+            state.error_count.store(0, Ordering::SeqCst);
+        }
+        
+        for state in self.node_metrics.read().await.values() {
+            state.messages_sent.store(0, Ordering::SeqCst);
+            state.messages_received.store(0, Ordering::SeqCst);
+            state.bytes_sent.store(0, Ordering::SeqCst);
+            state.bytes_received.store(0, Ordering::SeqCst);
+            state.error_count.store(0, Ordering::SeqCst);
+        }
+        
+        self.label_metrics.write().await.clear();
+        self.reset_global_counters();
+    }
+    
+    /// Reset system-wide counters
+    fn reset_global_counters(&self) {
         self.global_counters.total_messages_sent.store(0, Ordering::SeqCst);
         self.global_counters.total_messages_received.store(0, Ordering::SeqCst);
         self.global_counters.total_bytes_sent.store(0, Ordering::SeqCst);
         self.global_counters.total_bytes_received.store(0, Ordering::SeqCst);
         self.global_counters.total_errors.store(0, Ordering::SeqCst);
+        self.global_counters.dropped_label_pairs.store(0, Ordering::SeqCst);
+    }
+    
+    /// Clear all metrics
+    pub async fn clear_metrics(&self) {
+        self.transport_metrics.write().await.clear();
+        self.node_metrics.write().await.clear();
+        self.label_metrics.write().await.clear();
+        
+        // Reset global counters
+        self.reset_global_counters();
     }
     
     /// Export metrics to JSON
@@ -399,12 +595,128 @@ impl MetricsCollector {
         let export_data = MetricsExport {
             global: self.get_global_metrics().await,
             transports: self.get_all_transport_metrics().await,
+            throughput_by_mode: self.get_throughput_by_mode().await,
+            labels: self.get_all_label_metrics().await,
             nodes: self.get_all_node_metrics().await,
             timestamp: SystemTime::now(),
         };
         
         serde_json::to_string_pretty(&export_data)
     }
+    
+    /// Export metrics in the Prometheus text exposition format
+    pub async fn export_to_prometheus(&self) -> String {
+        let global = self.get_global_metrics().await;
+        let mut transports = self.get_all_transport_metrics().await;
+        transports.sort_by_key(|t| t.transport_type);
+        let labels = self.get_all_label_metrics().await;
+        
+        let mut out = PrometheusWriter::default();
+        
+        out.family("data_portal_uptime_seconds", "gauge", "Seconds since the collector was created");
+        out.sample("data_portal_uptime_seconds", &[], global.uptime_seconds as f64);
+        out.family("data_portal_active_nodes", "gauge", "Nodes that have exchanged messages");
+        out.sample("data_portal_active_nodes", &[], global.active_nodes as f64);
+        
+        let counters: [PrometheusFamily; 5] = [
+            ("data_portal_messages_sent_total", "Messages sent", |t| t.messages_sent as f64),
+            ("data_portal_messages_received_total", "Messages received", |t| t.messages_received as f64),
+            ("data_portal_bytes_sent_total", "Bytes sent", |t| t.bytes_sent as f64),
+            ("data_portal_bytes_received_total", "Bytes received", |t| t.bytes_received as f64),
+            ("data_portal_errors_total", "Failed operations", |t| t.error_count as f64),
+        ];
+        for (name, help, value) in counters {
+            out.family(name, "counter", help);
+            for transport in &transports {
+                let transport_label = format!("{:?}", transport.transport_type);
+                out.sample(name, &[("transport", &transport_label)], value(transport));
+            }
+        }
+        
+        let gauges: [PrometheusFamily; 3] = [
+            ("data_portal_average_latency_ms", "Average latency over the recent sample window", |t| t.average_latency_ms),
+            ("data_portal_average_throughput_mbps", "Average throughput over the recent sample window", |t| t.average_throughput_mbps),
+            ("data_portal_max_throughput_mbps", "Max throughput over the recent sample window", |t| t.max_throughput_mbps),
+        ];
+        for (name, help, value) in gauges {
+            out.family(name, "gauge", help);
+            for transport in &transports {
+                let transport_label = format!("{:?}", transport.transport_type);
+                out.sample(name, &[("transport", &transport_label)], value(transport));
+            }
+        }
+        
+        if !labels.is_empty() {
+            out.family("data_portal_label_bytes_sent_total", "counter", "Bytes sent by labeled operations");
+            for label in &labels {
+                out.sample(
+                    "data_portal_label_bytes_sent_total",
+                    &[("label_key", &label.key), ("label_value", &label.value)],
+                    label.bytes_sent as f64,
+                );
+            }
+            out.family("data_portal_label_bytes_received_total", "counter", "Bytes received by labeled operations");
+            for label in &labels {
+                out.sample(
+                    "data_portal_label_bytes_received_total",
+                    &[("label_key", &label.key), ("label_value", &label.value)],
+                    label.bytes_received as f64,
+                );
+            }
+        }
+        
+        out.finish()
+    }
+}
+
+/// Metric name, help text and value extractor for a per-transport family
+type PrometheusFamily = (&'static str, &'static str, fn(&TransportMetricsSummary) -> f64);
+
+/// Minimal writer for the Prometheus text exposition format
+#[derive(Default)]
+struct PrometheusWriter {
+    output: String,
+}
+
+impl PrometheusWriter {
+    /// Write the HELP and TYPE lines for a metric family
+    fn family(&mut self, name: &str, metric_type: &str, help: &str) {
+        self.output.push_str(&format!("# HELP {} {}\n# TYPE {} {}\n", name, help, name, metric_type));
+    }
+    
+    /// Write a single sample line
+    fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: f64) {
+        self.output.push_str(name);
+        if !labels.is_empty() {
+            let rendered: Vec<String> = labels.iter()
+                .map(|(key, value)| format!("{}=\"{}\"", key, escape_label_value(value)))
+                .collect();
+            self.output.push_str(&format!("{{{}}}", rendered.join(",")));
+        }
+        self.output.push_str(&format!(" {}\n", format_sample_value(value)));
+    }
+    
+    fn finish(self) -> String {
+        self.output
+    }
+}
+
+/// Format a sample value; the text format spells non-finite values as +Inf, -Inf and NaN
+fn format_sample_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value == f64::INFINITY {
+        "+Inf".to_string()
+    } else if value == f64::NEG_INFINITY {
+        "-Inf".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// Escape a label value for the Prometheus text format
+fn escape_label_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
 }
 
 impl Default for MetricsCollector {
@@ -423,6 +735,7 @@ pub struct TransportMetricsSummary {
     pub bytes_received: u64,
     pub average_latency_ms: f64,
     pub average_throughput_mbps: f64,
+    pub max_throughput_mbps: f64,
     pub error_count: u64,
     pub last_error: Option<String>,
     pub last_operation: Option<SystemTime>,
@@ -456,11 +769,63 @@ pub struct GlobalMetricsSummary {
     pub active_nodes: usize,
 }
 
+/// Metrics for operations tagged with one application label
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LabelMetricsSummary {
+    pub key: String,
+    pub value: String,
+    pub messages_sent: u64,
+    pub messages_received: u64,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub error_count: u64,
+}
+
+/// Throughput summary for one transport mode
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ModeThroughput {
+    pub bytes_transferred: u64,
+    pub average_throughput_mbps: f64,
+    pub max_throughput_mbps: f64,
+}
+
+/// Throughput split between shared memory and network transports
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ThroughputByMode {
+    pub shared_memory: ModeThroughput,
+    pub network: ModeThroughput,
+}
+
+/// Accumulates throughput samples across the transports of one mode
+#[derive(Default)]
+struct ModeThroughputAccumulator {
+    bytes: u64,
+    samples: Vec<f64>,
+}
+
+impl ModeThroughputAccumulator {
+    fn finish(self) -> ModeThroughput {
+        let average_throughput_mbps = if self.samples.is_empty() {
+            0.0
+        } else {
+            self.samples.iter().sum::<f64>() / self.samples.len() as f64
+        };
+        
+        ModeThroughput {
+            bytes_transferred: self.bytes,
+            average_throughput_mbps,
+            max_throughput_mbps: self.samples.iter().cloned().fold(0.0, f64::max),
+        }
+    }
+}
+
 /// Metrics export structure
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MetricsExport {
     pub global: GlobalMetricsSummary,
     pub transports: Vec<TransportMetricsSummary>,
+    pub throughput_by_mode: ThroughputByMode,
+    pub labels: Vec<LabelMetricsSummary>,
     pub nodes: Vec<NodeMetricsSummary>,
     pub timestamp: SystemTime,
 }
@@ -589,4 +954,199 @@ mod tests {
         assert!(json.contains("SharedMemory"));
         assert!(json.contains("1024"));
     }
-}
\ No newline at end of file
+    
+    #[tokio::test]
+    async fn test_throughput_by_mode() {
+        let collector = MetricsCollector::new();
+        let destination = NodeInfo::new("test_node", Language::Rust);
+        let mb = 1024 * 1024;
+        
+        // 1MB in 1ms and 1MB in 2ms over shared memory
+        collector.record_send(TransportType::SharedMemory, &destination, mb, 1.0, true, None).await;
+        collector.record_send(TransportType::SharedMemory, &destination, mb, 2.0, true, None).await;
+        // 1MB in 100ms over two network transports
+        collector.record_send(TransportType::RustNetwork, &destination, mb, 100.0, true, None).await;
+        collector.record_receive(TransportType::DataPortal, &destination, mb, 100.0, true, None).await;
+        
+        let throughput = collector.get_throughput_by_mode().await;
+        
+        assert_eq!(throughput.shared_memory.bytes_transferred, 2 * mb as u64);
+        assert!((throughput.shared_memory.max_throughput_mbps - 1000.0).abs() < 1e-6);
+        assert!((throughput.shared_memory.average_throughput_mbps - 750.0).abs() < 1e-6);
+        
+        assert_eq!(throughput.network.bytes_transferred, 2 * mb as u64);
+        assert!((throughput.network.max_throughput_mbps - 10.0).abs() < 1e-6);
+        assert!((throughput.network.average_throughput_mbps - 10.0).abs() < 1e-6);
+        
+        let summary = collector.get_transport_metrics(TransportType::SharedMemory).await.unwrap();
+        assert!((summary.max_throughput_mbps - 1000.0).abs() < 1e-6);
+    }
+    
+    #[tokio::test]
+    async fn test_zero_sample_window_clamped() {
+        let collector = MetricsCollector::with_config(MetricsConfig {
+            sample_window_size: 0,
+            sample_max_age: None,
+            ..Default::default()
+        });
+        let destination = NodeInfo::new("test_node", Language::Rust);
+        
+        collector.record_send(TransportType::SharedMemory, &destination, 1024, 2.0, true, None).await;
+        collector.record_send(TransportType::SharedMemory, &destination, 1024, 4.0, true, None).await;
+        
+        let metrics = collector.get_transport_metrics(TransportType::SharedMemory).await.unwrap();
+        assert_eq!(metrics.average_latency_ms, 4.0);
+    }
+    
+    #[tokio::test]
+    async fn test_sample_window_and_lifetime_counters() {
+        let collector = MetricsCollector::with_config(MetricsConfig {
+            sample_window_size: 2,
+            sample_max_age: None,
+            ..Default::default()
+        });
+        let destination = NodeInfo::new("test_node", Language::Rust);
+        
+        collector.record_send(TransportType::SharedMemory, &destination, 1024, 100.0, true, None).await;
+        collector.record_send(TransportType::SharedMemory, &destination, 1024, 2.0, true, None).await;
+        collector.record_send(TransportType::SharedMemory, &destination, 1024, 4.0, true, None).await;
+        
+        // Averages only cover the two most recent samples, counters cover everything
+        let metrics = collector.get_transport_metrics(TransportType::SharedMemory).await.unwrap();
+        assert_eq!(metrics.average_latency_ms, 3.0);
+        assert_eq!(metrics.messages_sent, 3);
+        assert_eq!(collector.get_global_metrics().await.total_bytes_sent, 3072);
+        
+        collector.reset_lifetime_counters().await;
+        
+        let metrics = collector.get_transport_metrics(TransportType::SharedMemory).await.unwrap();
+        assert_eq!(metrics.messages_sent, 0);
+        assert_eq!(metrics.average_latency_ms, 3.0);
+        assert_eq!(collector.get_global_metrics().await.total_bytes_sent, 0);
+        assert_eq!(collector.get_node_metrics("test_node").await.unwrap().bytes_sent, 0);
+    }
+    
+    #[tokio::test]
+    async fn test_sample_max_age() {
+        let collector = MetricsCollector::with_config(MetricsConfig {
+            sample_window_size: 100,
+            sample_max_age: Some(Duration::from_millis(50)),
+            ..Default::default()
+        });
+        let destination = NodeInfo::new("test_node", Language::Rust);
+        
+        collector.record_send(TransportType::RustNetwork, &destination, 1024, 100.0, true, None).await;
+        tokio::time::sleep(Duration::from_millis(80)).await;
+        collector.record_send(TransportType::RustNetwork, &destination, 1024, 10.0, true, None).await;
+        
+        let metrics = collector.get_transport_metrics(TransportType::RustNetwork).await.unwrap();
+        assert_eq!(metrics.average_latency_ms, 10.0);
+        assert_eq!(metrics.messages_sent, 2);
+    }
+    
+    #[tokio::test]
+    async fn test_labeled_operations() {
+        let collector = MetricsCollector::new();
+        let destination = NodeInfo::new("test_node", Language::Rust);
+        
+        let backup: HashMap<String, String> = [
+            ("job".to_string(), "backup-2024-06".to_string()),
+            ("user".to_string(), "alice".to_string()),
+        ].into_iter().collect();
+        let sync: HashMap<String, String> = [
+            ("job".to_string(), "sync".to_string()),
+            ("user".to_string(), "alice".to_string()),
+        ].into_iter().collect();
+        
+        collector.record_send_labeled(TransportType::SharedMemory, &destination, 1000, 1.0, true, &backup).await;
+        collector.record_send_labeled(TransportType::RustNetwork, &destination, 500, 1.0, true, &backup).await;
+        collector.record_receive_labeled(TransportType::RustNetwork, &destination, 200, 1.0, true, &sync).await;
+        
+        let backup_metrics = collector.get_label_metrics("job", "backup-2024-06").await.unwrap();
+        assert_eq!(backup_metrics.messages_sent, 2);
+        assert_eq!(backup_metrics.bytes_sent, 1500);
+        assert_eq!(backup_metrics.bytes_received, 0);
+        
+        let user_metrics = collector.get_label_metrics("user", "alice").await.unwrap();
+        assert_eq!(user_metrics.bytes_sent, 1500);
+        assert_eq!(user_metrics.bytes_received, 200);
+        
+        assert!(collector.get_label_metrics("job", "unknown").await.is_none());
+        assert_eq!(collector.get_all_label_metrics().await.len(), 3);
+        
+        // Labeled operations also count towards the regular metrics
+        assert_eq!(collector.get_global_metrics().await.total_bytes_sent, 1500);
+        
+        let json = collector.export_to_json().await.unwrap();
+        assert!(json.contains("backup-2024-06"));
+    }
+    
+    #[tokio::test]
+    async fn test_label_pairs_capped() {
+        let collector = MetricsCollector::with_config(MetricsConfig {
+            max_label_pairs: 2,
+            ..Default::default()
+        });
+        let destination = NodeInfo::new("test_node", Language::Rust);
+        let labels = |job: &str| -> HashMap<String, String> {
+            [("job".to_string(), job.to_string())].into_iter().collect()
+        };
+        
+        for job in ["a", "b", "c", "d"] {
+            collector.record_send_labeled(TransportType::SharedMemory, &destination, 100, 1.0, true, &labels(job)).await;
+        }
+        
+        // Pairs beyond the cap are dropped, already tracked pairs keep updating
+        collector.record_send_labeled(TransportType::SharedMemory, &destination, 100, 1.0, true, &labels("a")).await;
+        assert_eq!(collector.get_all_label_metrics().await.len(), 2);
+        assert_eq!(collector.get_label_metrics("job", "a").await.unwrap().messages_sent, 2);
+        assert!(collector.get_label_metrics("job", "c").await.is_none());
+        assert_eq!(collector.dropped_label_pairs(), 2);
+        
+        // Unlabeled metrics still count every operation
+        assert_eq!(collector.get_global_metrics().await.total_messages_sent, 5);
+        
+        collector.clear_metrics().await;
+        assert_eq!(collector.dropped_label_pairs(), 0);
+    }
+    
+    #[tokio::test]
+    async fn test_prometheus_export() {
+        let collector = MetricsCollector::new();
+        let destination = NodeInfo::new("test_node", Language::Rust);
+        let labels: HashMap<String, String> = [("job".to_string(), "nightly \"full\"".to_string())]
+            .into_iter()
+            .collect();
+        
+        collector.record_send(TransportType::SharedMemory, &destination, 1024, 5.0, true, None).await;
+        collector.record_send_labeled(TransportType::RustNetwork, &destination, 2048, 5.0, true, &labels).await;
+        
+        let text = collector.export_to_prometheus().await;
+        
+        assert!(text.contains("# TYPE data_portal_bytes_sent_total counter"));
+        assert!(text.contains("data_portal_bytes_sent_total{transport=\"SharedMemory\"} 1024"));
+        assert!(text.contains("data_portal_bytes_sent_total{transport=\"RustNetwork\"} 2048"));
+        assert!(text.contains("# TYPE data_portal_average_latency_ms gauge"));
+        assert!(text.contains("data_portal_active_nodes 1"));
+        assert!(text.contains("data_portal_label_bytes_sent_total{label_key=\"job\",label_value=\"nightly \\\"full\\\"\"} 2048"));
+    }
+
+    #[tokio::test]
+    async fn test_prometheus_export_non_finite_values() {
+        let collector = MetricsCollector::new();
+        let destination = NodeInfo::new("test_node", Language::Rust);
+        
+        // Zero latency yields an infinite throughput sample
+        collector.record_send(TransportType::SharedMemory, &destination, 1024, 0.0, true, None).await;
+        
+        let text = collector.export_to_prometheus().await;
+        assert!(text.contains("data_portal_max_throughput_mbps{transport=\"SharedMemory\"} +Inf"));
+        for line in text.lines().filter(|line| !line.starts_with('#')) {
+            let value = line.rsplit(' ').next().unwrap();
+            assert!(
+                matches!(value, "+Inf" | "-Inf" | "NaN") || value.parse::<f64>().is_ok_and(f64::is_finite),
+                "invalid sample value in {:?}", line
+            );
+        }
+    }
+}
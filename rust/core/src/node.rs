@@ -57,6 +57,11 @@ impl NodeInfo {
         self.machine_id == get_machine_id()
     }
     
+    /// Negotiate protocol version and features with a peer
+    pub fn negotiate(&self, peer: &NodeInfo) -> crate::Result<NegotiatedCapabilities> {
+        self.capabilities.negotiate(&peer.capabilities)
+    }
+    
     /// Get the shared memory region name for communication with this node
     pub fn get_shared_memory_name(&self, other: &NodeInfo) -> String {
         let mut ids = vec![&self.id, &other.id];
@@ -85,6 +90,58 @@ pub struct NodeCapabilities {
     pub supports_encryption: bool,
     /// Protocol version
     pub protocol_version: String,
+    /// Binary protocol versions this node can speak
+    #[serde(default = "default_protocol_versions")]
+    pub supported_protocol_versions: Vec<u8>,
+}
+
+/// Result of a capability exchange between two nodes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NegotiatedCapabilities {
+    /// Highest binary protocol version both nodes support
+    pub protocol_version: u8,
+    /// Transports supported by both nodes
+    pub transports: Vec<crate::TransportType>,
+    /// Largest message both nodes accept
+    pub max_message_size: usize,
+    /// Both nodes support compression
+    pub compression: bool,
+    /// Both nodes support encryption
+    pub encryption: bool,
+}
+
+impl NodeCapabilities {
+    /// Pick the highest common protocol version and the shared feature set
+    pub fn negotiate(&self, peer: &NodeCapabilities) -> crate::Result<NegotiatedCapabilities> {
+        let protocol_version = self.supported_protocol_versions.iter()
+            .filter(|version| peer.supported_protocol_versions.contains(version))
+            .max()
+            .copied()
+            .ok_or_else(|| crate::TransportError::VersionMismatch {
+                expected: format!("{:?}", self.supported_protocol_versions),
+                actual: format!("{:?}", peer.supported_protocol_versions),
+            })?;
+        
+        // Canonical order so both sides of the exchange agree
+        let mut transports: Vec<_> = self.supported_transports.iter()
+            .filter(|transport| peer.supported_transports.contains(transport))
+            .copied()
+            .collect();
+        transports.sort();
+        transports.dedup();
+        
+        Ok(NegotiatedCapabilities {
+            protocol_version,
+            transports,
+            max_message_size: self.max_message_size.min(peer.max_message_size),
+            compression: self.supports_compression && peer.supports_compression,
+            encryption: self.supports_encryption && peer.supports_encryption,
+        })
+    }
+}
+
+fn default_protocol_versions() -> Vec<u8> {
+    vec![crate::binary_protocol::PROTOCOL_VERSION]
 }
 
 impl Default for NodeCapabilities {
@@ -97,6 +154,7 @@ impl Default for NodeCapabilities {
             supports_compression: false,
             supports_encryption: false,
             protocol_version: crate::VERSION.to_string(),
+            supported_protocol_versions: default_protocol_versions(),
         }
     }
 }
@@ -145,4 +203,49 @@ mod tests {
         assert!(name.contains("node1"));
         assert!(name.contains("node2"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_protocol_negotiation() {
+        let v1 = NodeInfo::new("v1", Language::Rust);
+        let mut v2 = NodeInfo::new("v2", Language::Swift);
+        v2.capabilities.supported_protocol_versions = vec![1, 2];
+        v2.capabilities.supports_compression = true;
+        v2.capabilities.max_message_size = 16 * 1024 * 1024;
+        
+        let negotiated = v1.negotiate(&v2).unwrap();
+        assert_eq!(negotiated, v2.negotiate(&v1).unwrap());
+        assert_eq!(negotiated.protocol_version, 1);
+        assert_eq!(negotiated.transports, vec![crate::TransportType::DataPortal]);
+        assert_eq!(negotiated.max_message_size, 16 * 1024 * 1024);
+        assert!(!negotiated.compression);
+        
+        // Shared transports listed in different orders still negotiate identically
+        let mut a = NodeInfo::new("a", Language::Rust);
+        a.capabilities.supported_transports = vec![
+            crate::TransportType::DataPortal,
+            crate::TransportType::RustNetwork,
+            crate::TransportType::SharedMemory,
+        ];
+        let mut b = NodeInfo::new("b", Language::Rust);
+        b.capabilities.supported_transports = vec![
+            crate::TransportType::SharedMemory,
+            crate::TransportType::SwiftNetwork,
+            crate::TransportType::RustNetwork,
+            crate::TransportType::DataPortal,
+        ];
+        let negotiated = a.negotiate(&b).unwrap();
+        assert_eq!(negotiated, b.negotiate(&a).unwrap());
+        assert_eq!(negotiated.transports, vec![
+            crate::TransportType::SharedMemory,
+            crate::TransportType::RustNetwork,
+            crate::TransportType::DataPortal,
+        ]);
+        
+        let mut v3 = NodeInfo::new("v3", Language::Rust);
+        v3.capabilities.supported_protocol_versions = vec![3];
+        assert!(matches!(
+            v1.negotiate(&v3),
+            Err(crate::TransportError::VersionMismatch { .. })
+        ));
+    }
+}
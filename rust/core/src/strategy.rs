@@ -3,11 +3,19 @@
 use crate::{NodeInfo, TransportType, TransportError, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use tracing::debug;
+
+/// Minimum success rate for a transport to be chosen on RTT alone
+const MIN_RTT_CANDIDATE_SUCCESS_RATE: f64 = 0.5;
 
 /// Transport strategy selector
 pub struct StrategySelector {
     /// Performance history for different nodes
     performance_history: HashMap<String, PerformanceHistory>,
+    /// Measured round-trip times for different nodes
+    rtt_history: HashMap<String, RttHistory>,
+    /// Optional policy consulted before the built-in selection
+    policy: Option<Box<dyn SelectionPolicy>>,
     /// Strategy preferences
     preferences: StrategyPreferences,
 }
@@ -48,6 +56,15 @@ pub struct PerformanceHistory {
     pub last_updated: std::time::SystemTime,
 }
 
+/// Measured round-trip times for a node
+#[derive(Debug, Clone)]
+pub struct RttHistory {
+    /// Transport type to smoothed RTT in milliseconds
+    pub rtt_ms: HashMap<TransportType, f64>,
+    /// Last update timestamp
+    pub last_updated: std::time::SystemTime,
+}
+
 /// Performance metrics for a transport type
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PerformanceMetrics {
@@ -74,11 +91,100 @@ pub enum TransportStrategy {
     DataPortal { endpoint: String },
 }
 
+/// Peer information available to a selection policy
+#[derive(Debug, Clone, Copy)]
+pub struct SelectionContext<'a> {
+    /// Sending node
+    pub source: &'a NodeInfo,
+    /// Receiving node
+    pub destination: &'a NodeInfo,
+    /// Payload size in bytes
+    pub data_size: usize,
+    /// Lowest measured network RTT to the destination, if any
+    pub rtt_ms: Option<f64>,
+}
+
+/// Pluggable transport selection policy
+///
+/// Returning `None` (or a transport the destination cannot use) defers to
+/// the selector's built-in RTT, performance and default selection.
+pub trait SelectionPolicy: Send + Sync {
+    /// Policy name for logging
+    fn name(&self) -> &str;
+    
+    /// Choose a transport type for the given context
+    fn select(&self, context: &SelectionContext<'_>) -> Option<TransportType>;
+}
+
+/// Use shared memory whenever the destination is co-located
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PreferSharedMemory;
+
+impl SelectionPolicy for PreferSharedMemory {
+    fn name(&self) -> &str {
+        "prefer_shared_memory"
+    }
+    
+    fn select(&self, context: &SelectionContext<'_>) -> Option<TransportType> {
+        if context.destination.is_local_machine() {
+            Some(TransportType::SharedMemory)
+        } else {
+            None
+        }
+    }
+}
+
+/// Always use the destination's language-optimized network transport
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PreferNetwork;
+
+impl SelectionPolicy for PreferNetwork {
+    fn name(&self) -> &str {
+        "prefer_network"
+    }
+    
+    fn select(&self, context: &SelectionContext<'_>) -> Option<TransportType> {
+        Some(network_transport_for(context.destination))
+    }
+}
+
+/// Use shared memory for co-located payloads of at least `threshold` bytes,
+/// and the network otherwise
+#[derive(Debug, Clone, Copy)]
+pub struct SizeThreshold {
+    /// Minimum payload size for shared memory (bytes)
+    pub threshold: usize,
+}
+
+impl SelectionPolicy for SizeThreshold {
+    fn name(&self) -> &str {
+        "size_threshold"
+    }
+    
+    fn select(&self, context: &SelectionContext<'_>) -> Option<TransportType> {
+        if context.destination.is_local_machine() && context.data_size >= self.threshold {
+            Some(TransportType::SharedMemory)
+        } else {
+            Some(network_transport_for(context.destination))
+        }
+    }
+}
+
+/// Language-optimized network transport for a node
+fn network_transport_for(node: &NodeInfo) -> TransportType {
+    match node.language {
+        crate::Language::Swift => TransportType::SwiftNetwork,
+        crate::Language::Rust => TransportType::RustNetwork,
+    }
+}
+
 impl StrategySelector {
     /// Create a new strategy selector
     pub fn new(preferences: StrategyPreferences) -> Self {
         Self {
             performance_history: HashMap::new(),
+            rtt_history: HashMap::new(),
+            policy: None,
             preferences,
         }
     }
@@ -95,6 +201,23 @@ impl StrategySelector {
         destination: &NodeInfo,
         data_size: usize,
     ) -> Result<TransportStrategy> {
+        // 0. Consult the registered policy, if any
+        if let Some(policy) = &self.policy {
+            let context = SelectionContext {
+                source,
+                destination,
+                data_size,
+                rtt_ms: self.lowest_network_rtt(destination),
+            };
+            
+            if let Some(strategy) = policy.select(&context)
+                .and_then(|transport_type| self.policy_strategy(transport_type, source, destination))
+            {
+                debug!("Policy {} selected {:?} for {}", policy.name(), strategy.transport_type(), destination.id);
+                return Ok(strategy);
+            }
+        }
+        
         // 1. Check if same machine - prefer shared memory
         if self.preferences.prefer_shared_memory && destination.is_local_machine() {
             if data_size >= self.preferences.shared_memory_threshold {
@@ -103,12 +226,18 @@ impl StrategySelector {
             }
         }
         
-        // 2. Check for existing performance data
+        // 2. Otherwise (remote peers, or local payloads below the shared memory
+        //    threshold) pick the reliable network path with the lowest measured RTT
+        if let Some(fastest_strategy) = self.get_lowest_rtt_strategy(destination) {
+            return Ok(fastest_strategy);
+        }
+        
+        // 3. Check for existing performance data
         if let Some(best_strategy) = self.get_best_performing_strategy(destination) {
             return Ok(best_strategy);
         }
         
-        // 3. Default selection based on node characteristics
+        // 4. Default selection based on node characteristics
         self.select_default_strategy(destination)
     }
     
@@ -134,6 +263,49 @@ impl StrategySelector {
         })
     }
     
+    /// Convert a policy decision to a strategy, naming shared memory regions after the pair
+    fn policy_strategy(&self, transport_type: TransportType, source: &NodeInfo, destination: &NodeInfo) -> Option<TransportStrategy> {
+        match transport_type {
+            TransportType::SharedMemory if destination.is_local_machine() => {
+                let region_name = source.get_shared_memory_name(destination);
+                Some(TransportStrategy::SharedMemory { region_name })
+            }
+            _ => self.transport_type_to_strategy(transport_type, destination),
+        }
+    }
+    
+    /// Get the lowest measured network RTT for a destination
+    fn lowest_network_rtt(&self, destination: &NodeInfo) -> Option<f64> {
+        self.rtt_history.get(&destination.id)?
+            .rtt_ms.iter()
+            .filter(|(transport_type, _)| **transport_type != TransportType::SharedMemory)
+            .map(|(_, rtt_ms)| *rtt_ms)
+            .min_by(f64::total_cmp)
+    }
+    
+    /// Get the network strategy with the lowest measured RTT, skipping
+    /// transports whose recorded success rate is too low to rely on
+    fn get_lowest_rtt_strategy(&self, destination: &NodeInfo) -> Option<TransportStrategy> {
+        let history = self.rtt_history.get(&destination.id)?;
+        let performance = self.performance_history.get(&destination.id);
+        
+        let (transport_type, rtt_ms) = history.rtt_ms.iter()
+            .filter(|(transport_type, _)| **transport_type != TransportType::SharedMemory)
+            .filter(|(transport_type, _)| {
+                performance
+                    .and_then(|performance| performance.metrics.get(*transport_type))
+                    .is_none_or(|metrics| metrics.success_rate >= MIN_RTT_CANDIDATE_SUCCESS_RATE)
+            })
+            .min_by(|a, b| a.1.total_cmp(b.1))?;
+        
+        debug!(
+            "Selected {:?} for {} with measured RTT {:.2}ms",
+            transport_type, destination.id, rtt_ms
+        );
+        
+        self.transport_type_to_strategy(*transport_type, destination)
+    }
+    
     /// Calculate performance score for metrics
     fn calculate_performance_score(&self, metrics: &PerformanceMetrics) -> f64 {
         // Weighted score: success rate (50%) + latency factor (30%) + throughput factor (20%)
@@ -258,6 +430,30 @@ impl StrategySelector {
         history.last_updated = std::time::SystemTime::now();
     }
     
+    /// Record a measured round-trip time, e.g. from a ping probe
+    pub fn record_rtt(&mut self, node_id: &str, transport_type: TransportType, rtt: std::time::Duration) {
+        let rtt_ms = rtt.as_secs_f64() * 1000.0;
+        let history = self.rtt_history
+            .entry(node_id.to_string())
+            .or_insert_with(|| RttHistory {
+                rtt_ms: HashMap::new(),
+                last_updated: std::time::SystemTime::now(),
+            });
+        
+        // Smooth with the same exponential moving average as performance metrics
+        let alpha = 0.1;
+        history.rtt_ms
+            .entry(transport_type)
+            .and_modify(|avg| *avg = alpha * rtt_ms + (1.0 - alpha) * *avg)
+            .or_insert(rtt_ms);
+        history.last_updated = std::time::SystemTime::now();
+    }
+    
+    /// Get measured round-trip times for a node
+    pub fn get_rtt_history(&self, node_id: &str) -> Option<&RttHistory> {
+        self.rtt_history.get(node_id)
+    }
+    
     /// Get performance history for a node
     pub fn get_performance_history(&self, node_id: &str) -> Option<&PerformanceHistory> {
         self.performance_history.get(node_id)
@@ -270,6 +466,24 @@ impl StrategySelector {
         self.performance_history.retain(|_, history| {
             history.last_updated > cutoff
         });
+        self.rtt_history.retain(|_, history| {
+            history.last_updated > cutoff
+        });
+    }
+    
+    /// Register a selection policy, replacing any previous one
+    pub fn set_policy(&mut self, policy: Box<dyn SelectionPolicy>) {
+        self.policy = Some(policy);
+    }
+    
+    /// Remove the registered selection policy
+    pub fn clear_policy(&mut self) {
+        self.policy = None;
+    }
+    
+    /// Get the name of the registered selection policy
+    pub fn policy_name(&self) -> Option<&str> {
+        self.policy.as_deref().map(|policy| policy.name())
     }
     
     /// Get strategy preferences
@@ -433,4 +647,137 @@ mod tests {
         assert_eq!(network_strategy.endpoint(), Some("127.0.0.1:8080"));
         assert_eq!(network_strategy.region_name(), None);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_rtt_aware_selection() {
+        let mut selector = StrategySelector::new_default();
+        let source = NodeInfo::new("source", Language::Rust);
+        let remote = NodeInfo::remote("remote", Language::Rust, "10.0.0.2:8080");
+        
+        // Language default without measurements
+        let strategy = selector.select_strategy(&source, &remote, 4096).unwrap();
+        assert_eq!(strategy.transport_type(), TransportType::RustNetwork);
+        
+        // Synthetic RTTs: the compatibility path is faster on this segment
+        selector.record_rtt("remote", TransportType::RustNetwork, std::time::Duration::from_millis(40));
+        selector.record_rtt("remote", TransportType::DataPortal, std::time::Duration::from_millis(5));
+        selector.record_rtt("remote", TransportType::SharedMemory, std::time::Duration::from_micros(10));
+        
+        let strategy = selector.select_strategy(&source, &remote, 4096).unwrap();
+        assert_eq!(strategy.transport_type(), TransportType::DataPortal);
+        assert_eq!(selector.get_rtt_history("remote").unwrap().rtt_ms.len(), 3);
+        
+        // Co-located peers still use shared memory regardless of RTT
+        let local = NodeInfo::new("local", Language::Rust);
+        selector.record_rtt("local", TransportType::RustNetwork, std::time::Duration::from_micros(50));
+        let strategy = selector.select_strategy(&source, &local, 4096).unwrap();
+        assert_eq!(strategy.transport_type(), TransportType::SharedMemory);
+    }
+
+    #[test]
+    fn test_rtt_selection_skips_unreliable_transport() {
+        let mut selector = StrategySelector::new_default();
+        let source = NodeInfo::new("source", Language::Rust);
+        let remote = NodeInfo::remote("remote", Language::Rust, "10.0.0.2:8080");
+        
+        selector.record_rtt("remote", TransportType::DataPortal, std::time::Duration::from_millis(5));
+        selector.record_rtt("remote", TransportType::RustNetwork, std::time::Duration::from_millis(40));
+        
+        // The low-RTT path keeps failing, so the slower reliable one wins
+        selector.update_performance("remote", TransportType::DataPortal, 5.0, 100.0, false);
+        selector.update_performance("remote", TransportType::RustNetwork, 40.0, 100.0, true);
+        
+        let strategy = selector.select_strategy(&source, &remote, 4096).unwrap();
+        assert_eq!(strategy.transport_type(), TransportType::RustNetwork);
+    }
+
+    #[test]
+    fn test_prefer_shared_memory_policy() {
+        let mut selector = StrategySelector::new_default();
+        selector.set_policy(Box::new(PreferSharedMemory));
+        assert_eq!(selector.policy_name(), Some("prefer_shared_memory"));
+        
+        let source = NodeInfo::new("source", Language::Rust);
+        let local = NodeInfo::new("local", Language::Rust);
+        let remote = NodeInfo::remote("remote", Language::Swift, "10.0.0.2:8080");
+        
+        // Below the preference threshold the policy still picks shared memory
+        let strategy = selector.select_strategy(&source, &local, 16).unwrap();
+        assert_eq!(strategy, TransportStrategy::SharedMemory { region_name: source.get_shared_memory_name(&local) });
+        
+        // Remote peers defer to the built-in selection
+        let strategy = selector.select_strategy(&source, &remote, 16).unwrap();
+        assert_eq!(strategy.transport_type(), TransportType::SwiftNetwork);
+    }
+
+    #[test]
+    fn test_prefer_network_policy() {
+        let mut selector = StrategySelector::new_default();
+        selector.set_policy(Box::new(PreferNetwork));
+        
+        let source = NodeInfo::new("source", Language::Rust);
+        let mut local = NodeInfo::new("local", Language::Rust);
+        local.endpoint = Some("127.0.0.1:9000".to_string());
+        
+        let strategy = selector.select_strategy(&source, &local, 1024 * 1024).unwrap();
+        assert_eq!(strategy, TransportStrategy::RustNetwork { endpoint: "127.0.0.1:9000".to_string() });
+        
+        // Without an endpoint the network choice is unusable and shared memory wins
+        let local = NodeInfo::new("local", Language::Rust);
+        let strategy = selector.select_strategy(&source, &local, 1024 * 1024).unwrap();
+        assert_eq!(strategy.transport_type(), TransportType::SharedMemory);
+    }
+
+    #[test]
+    fn test_size_threshold_policy() {
+        let mut selector = StrategySelector::new_default();
+        selector.set_policy(Box::new(SizeThreshold { threshold: 64 * 1024 }));
+        
+        let source = NodeInfo::new("source", Language::Rust);
+        let mut local = NodeInfo::new("local", Language::Swift);
+        local.endpoint = Some("127.0.0.1:9000".to_string());
+        
+        let strategy = selector.select_strategy(&source, &local, 64 * 1024).unwrap();
+        assert_eq!(strategy.transport_type(), TransportType::SharedMemory);
+        
+        let strategy = selector.select_strategy(&source, &local, 4 * 1024).unwrap();
+        assert_eq!(strategy.transport_type(), TransportType::SwiftNetwork);
+        
+        let remote = NodeInfo::remote("remote", Language::Rust, "10.0.0.2:8080");
+        let strategy = selector.select_strategy(&source, &remote, 1024 * 1024).unwrap();
+        assert_eq!(strategy.transport_type(), TransportType::RustNetwork);
+    }
+
+    #[test]
+    fn test_custom_policy_sees_rtt() {
+        struct RttCutoff;
+        
+        impl SelectionPolicy for RttCutoff {
+            fn name(&self) -> &str {
+                "rtt_cutoff"
+            }
+            
+            fn select(&self, context: &SelectionContext<'_>) -> Option<TransportType> {
+                match context.rtt_ms {
+                    Some(rtt_ms) if rtt_ms > 20.0 => Some(TransportType::DataPortal),
+                    _ => None,
+                }
+            }
+        }
+        
+        let mut selector = StrategySelector::new_default();
+        selector.set_policy(Box::new(RttCutoff));
+        
+        let source = NodeInfo::new("source", Language::Rust);
+        let remote = NodeInfo::remote("remote", Language::Rust, "10.0.0.2:8080");
+        
+        selector.record_rtt("remote", TransportType::RustNetwork, std::time::Duration::from_millis(50));
+        let strategy = selector.select_strategy(&source, &remote, 1024).unwrap();
+        assert_eq!(strategy.transport_type(), TransportType::DataPortal);
+        
+        selector.clear_policy();
+        assert_eq!(selector.policy_name(), None);
+        let strategy = selector.select_strategy(&source, &remote, 1024).unwrap();
+        assert_eq!(strategy.transport_type(), TransportType::RustNetwork);
+    }
+}
@@ -46,8 +46,8 @@ pub trait DataPortalTransport: Send + Sync {
     async fn available_transports(&self) -> Vec<TransportInfo>;
 }
 
-/// Transport type enumeration
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Transport type enumeration, ordered from most to least local
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum TransportType {
     /// Shared memory transport (same machine)
     SharedMemory,
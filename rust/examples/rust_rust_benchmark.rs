@@ -91,6 +91,7 @@ impl RustRustBenchmark {
             heartbeat_interval: Duration::from_secs(5),
             max_retries: 3,
             enable_optimizations: true,
+            verify_checksums: true,
         };
         
         let transport = Arc::new(SharedMemoryTransportAdapter::new(config));
@@ -0,0 +1,315 @@
+//! On-the-wire payload compression
+//! 
+//! Payloads are compressed only while in flight; the receiver always hands
+//! the original bytes to the application, so storage formats are unaffected.
+
+use crate::{NetworkConfig, NetworkError, Result, FLAG_COMPRESSED, FLAG_CUSTOM_CODEC};
+use bytes::Bytes;
+use std::fmt;
+use std::sync::Arc;
+
+/// Payloads smaller than this are always sent raw
+pub const MIN_COMPRESSIBLE_SIZE: usize = 512;
+
+/// Size of the length prefix lz4 prepends to compressed payloads
+const LZ4_SIZE_PREFIX: usize = 4;
+
+/// User-supplied compression codec, e.g. for domain-specific data
+pub trait Compressor: Send + Sync {
+    /// Codec name for logging
+    fn name(&self) -> &str;
+    
+    /// Codec identifier written ahead of every payload it produces, so a
+    /// peer with a different custom codec rejects the payload
+    fn id(&self) -> u8;
+    
+    /// Compress a payload
+    fn compress(&self, data: &[u8]) -> Result<Vec<u8>>;
+    
+    /// Decompress a payload; implementations should refuse output larger than `max_size`
+    fn decompress(&self, data: &[u8], max_size: usize) -> Result<Vec<u8>>;
+}
+
+/// Compression algorithm used for outgoing payloads
+#[derive(Clone, Default)]
+pub enum CompressionAlgorithm {
+    /// Built-in lz4 block compression
+    #[default]
+    Lz4,
+    /// User-supplied codec; both peers must register the same one
+    Custom(Arc<dyn Compressor>),
+}
+
+impl fmt::Debug for CompressionAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompressionAlgorithm::Lz4 => write!(f, "Lz4"),
+            CompressionAlgorithm::Custom(compressor) => write!(f, "Custom({})", compressor.name()),
+        }
+    }
+}
+
+/// Codec applied to payloads as they are written to and read from the wire
+#[derive(Debug, Clone)]
+pub struct WireCodec {
+    /// Compress outgoing payloads
+    enabled: bool,
+    /// Algorithm for outgoing payloads
+    algorithm: CompressionAlgorithm,
+    /// Largest decompressed payload accepted
+    max_message_size: usize,
+}
+
+impl WireCodec {
+    /// Negotiate a codec: outgoing compression is enabled only when both the
+    /// local configuration and the peer support it
+    pub fn negotiate(config: &NetworkConfig, peer_supports_compression: bool) -> Self {
+        Self {
+            enabled: config.enable_compression && peer_supports_compression,
+            algorithm: CompressionAlgorithm::Lz4,
+            max_message_size: config.max_message_size,
+        }
+    }
+    
+    /// Use the given algorithm for outgoing payloads
+    pub fn with_algorithm(mut self, algorithm: CompressionAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+    
+    /// Algorithm used for outgoing payloads
+    pub fn algorithm(&self) -> &CompressionAlgorithm {
+        &self.algorithm
+    }
+    
+    /// Whether outgoing payloads are compressed
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+    
+    /// Encode a payload for the wire, returning the bytes and header flags.
+    /// Payloads that do not shrink are sent raw.
+    pub fn encode(&self, payload: &[u8]) -> Result<(Bytes, u16)> {
+        if !self.enabled || payload.len() < MIN_COMPRESSIBLE_SIZE {
+            return Ok((Bytes::copy_from_slice(payload), 0));
+        }
+        
+        let (compressed, flags) = match &self.algorithm {
+            CompressionAlgorithm::Lz4 => {
+                let compressed = lz4::block::compress(payload, None, true)
+                    .map_err(|e| NetworkError::Compression(e.to_string()))?;
+                (compressed, FLAG_COMPRESSED)
+            }
+            CompressionAlgorithm::Custom(compressor) => {
+                let mut tagged = vec![compressor.id()];
+                tagged.extend_from_slice(&compressor.compress(payload)?);
+                (tagged, FLAG_COMPRESSED | FLAG_CUSTOM_CODEC)
+            }
+        };
+        
+        if compressed.len() >= payload.len() {
+            Ok((Bytes::copy_from_slice(payload), 0))
+        } else {
+            Ok((Bytes::from(compressed), flags))
+        }
+    }
+    
+    /// Decode a payload received with the given header flags
+    pub fn decode(&self, payload: &[u8], flags: u16) -> Result<Bytes> {
+        if flags & FLAG_COMPRESSED == 0 {
+            return Ok(Bytes::copy_from_slice(payload));
+        }
+        
+        if flags & FLAG_CUSTOM_CODEC != 0 {
+            return self.decode_custom(payload);
+        }
+        
+        if payload.len() < LZ4_SIZE_PREFIX {
+            return Err(NetworkError::Compression("Compressed payload is truncated".to_string()));
+        }
+        
+        // Check the declared size before allocating the output buffer
+        let declared_size = u32::from_le_bytes([payload[0], payload[1], payload[2], payload[3]]) as usize;
+        if declared_size > self.max_message_size {
+            return Err(NetworkError::Compression(format!(
+                "Decompressed size {} exceeds max_message_size {}",
+                declared_size, self.max_message_size
+            )));
+        }
+        
+        lz4::block::decompress(payload, None)
+            .map(Bytes::from)
+            .map_err(|e| NetworkError::Compression(e.to_string()))
+    }
+    
+    /// Decode a payload produced by a custom codec
+    fn decode_custom(&self, payload: &[u8]) -> Result<Bytes> {
+        let compressor = match &self.algorithm {
+            CompressionAlgorithm::Custom(compressor) => compressor,
+            CompressionAlgorithm::Lz4 => {
+                return Err(NetworkError::Compression(
+                    "Payload uses a custom codec but none is registered".to_string()
+                ));
+            }
+        };
+        
+        let (codec_id, body) = payload.split_first()
+            .ok_or_else(|| NetworkError::Compression("Compressed payload is truncated".to_string()))?;
+        if *codec_id != compressor.id() {
+            return Err(NetworkError::Compression(format!(
+                "Payload was produced by custom codec {} but {} ({}) is registered",
+                codec_id, compressor.name(), compressor.id()
+            )));
+        }
+        
+        let decompressed = compressor.decompress(body, self.max_message_size)?;
+        if decompressed.len() > self.max_message_size {
+            return Err(NetworkError::Compression(format!(
+                "Decompressed size {} exceeds max_message_size {}",
+                decompressed.len(), self.max_message_size
+            )));
+        }
+        
+        Ok(Bytes::from(decompressed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn enabled_codec() -> WireCodec {
+        let config = NetworkConfig::builder().enable_compression(true).build().unwrap();
+        WireCodec::negotiate(&config, true)
+    }
+    
+    #[test]
+    fn test_negotiation() {
+        let config = NetworkConfig::builder().enable_compression(true).build().unwrap();
+        assert!(WireCodec::negotiate(&config, true).is_enabled());
+        assert!(!WireCodec::negotiate(&config, false).is_enabled());
+        assert!(!WireCodec::negotiate(&NetworkConfig::default(), true).is_enabled());
+    }
+    
+    #[test]
+    fn test_compressible_round_trip() {
+        let codec = enabled_codec();
+        let original = b"data portal ".repeat(1024);
+        
+        let (wire, flags) = codec.encode(&original).unwrap();
+        assert_eq!(flags, FLAG_COMPRESSED);
+        assert!(wire.len() < original.len());
+        
+        let decoded = codec.decode(&wire, flags).unwrap();
+        assert_eq!(decoded.as_ref(), original.as_slice());
+    }
+    
+    #[test]
+    fn test_incompressible_data_stays_raw() {
+        let codec = enabled_codec();
+        let mut state = 0x2545F491u32;
+        let original: Vec<u8> = (0..4096).map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        }).collect();
+        
+        let (wire, flags) = codec.encode(&original).unwrap();
+        assert_eq!(flags, 0);
+        assert_eq!(wire.as_ref(), original.as_slice());
+    }
+    
+    #[test]
+    fn test_decode_rejects_oversized_declared_length() {
+        let config = NetworkConfig::builder()
+            .enable_compression(true)
+            .buffer_size(1024)
+            .max_message_size(1024)
+            .build()
+            .unwrap();
+        let codec = WireCodec::negotiate(&config, true);
+        
+        let mut payload = (u32::MAX).to_le_bytes().to_vec();
+        payload.extend_from_slice(&[0u8; 16]);
+        
+        assert!(matches!(codec.decode(&payload, FLAG_COMPRESSED), Err(NetworkError::Compression(_))));
+    }
+    
+    /// Run-length codec used to exercise the custom hook
+    struct RunLength;
+    
+    impl Compressor for RunLength {
+        fn name(&self) -> &str {
+            "run_length"
+        }
+        
+        fn id(&self) -> u8 {
+            1
+        }
+        
+        fn compress(&self, data: &[u8]) -> Result<Vec<u8>> {
+            let mut out = Vec::new();
+            for run in data.chunk_by(|a, b| a == b) {
+                for part in run.chunks(u8::MAX as usize) {
+                    out.push(part.len() as u8);
+                    out.push(part[0]);
+                }
+            }
+            Ok(out)
+        }
+        
+        fn decompress(&self, data: &[u8], max_size: usize) -> Result<Vec<u8>> {
+            let mut out = Vec::new();
+            for pair in data.chunks(2) {
+                if pair.len() != 2 || out.len() + pair[0] as usize > max_size {
+                    return Err(NetworkError::Compression("Invalid run-length payload".to_string()));
+                }
+                out.extend(std::iter::repeat_n(pair[1], pair[0] as usize));
+            }
+            Ok(out)
+        }
+    }
+    
+    /// Second custom codec with a different id
+    struct Passthrough;
+    
+    impl Compressor for Passthrough {
+        fn name(&self) -> &str {
+            "passthrough"
+        }
+        
+        fn id(&self) -> u8 {
+            2
+        }
+        
+        fn compress(&self, data: &[u8]) -> Result<Vec<u8>> {
+            Ok(data.to_vec())
+        }
+        
+        fn decompress(&self, data: &[u8], _max_size: usize) -> Result<Vec<u8>> {
+            Ok(data.to_vec())
+        }
+    }
+    
+    #[test]
+    fn test_custom_codec_round_trip() {
+        let codec = enabled_codec().with_algorithm(CompressionAlgorithm::Custom(Arc::new(RunLength)));
+        assert_eq!(format!("{:?}", codec.algorithm()), "Custom(run_length)");
+        
+        let original = [vec![7u8; 2000], vec![0u8; 3000]].concat();
+        let (wire, flags) = codec.encode(&original).unwrap();
+        assert_eq!(flags, FLAG_COMPRESSED | FLAG_CUSTOM_CODEC);
+        assert!(wire.len() < 100);
+        
+        let decoded = codec.decode(&wire, flags).unwrap();
+        assert_eq!(decoded.as_ref(), original.as_slice());
+        
+        // A peer without the custom codec refuses rather than misreading the payload
+        assert!(matches!(enabled_codec().decode(&wire, flags), Err(NetworkError::Compression(_))));
+        
+        // So does a peer that registered a different custom codec
+        let other = enabled_codec().with_algorithm(CompressionAlgorithm::Custom(Arc::new(Passthrough)));
+        assert!(matches!(other.decode(&wire, flags), Err(NetworkError::Compression(_))));
+    }
+}
@@ -0,0 +1,36 @@
+//! Network specific error types
+
+use thiserror::Error;
+
+/// Network error types
+#[derive(Error, Debug)]
+pub enum NetworkError {
+    /// Invalid configuration value
+    #[error("Invalid configuration for {field}: {reason}")]
+    InvalidConfig { field: &'static str, reason: String },
+    
+    /// Payload compression or decompression failed
+    #[error("Compression error: {0}")]
+    Compression(String),
+    
+    /// Malformed or unsupported message header
+    #[error("Protocol error: {0}")]
+    Protocol(String),
+    
+    /// Declared payload exceeds the configured limit
+    #[error("Message size {size} exceeds max_message_size {max}")]
+    MessageTooLarge { size: usize, max: usize },
+}
+
+/// Convenience type alias
+pub type Result<T> = std::result::Result<T, NetworkError>;
+
+impl NetworkError {
+    /// Create an invalid configuration error
+    pub fn invalid_config(field: &'static str, reason: impl Into<String>) -> Self {
+        NetworkError::InvalidConfig {
+            field,
+            reason: reason.into(),
+        }
+    }
+}
@@ -6,8 +6,14 @@ pub mod protocol;
 pub mod swift;
 pub mod rust_transport;
 pub mod data_portal;
+pub mod error;
+#[cfg(feature = "compression")]
+pub mod compression;
 
 pub use protocol::*;
+pub use error::*;
+#[cfg(feature = "compression")]
+pub use compression::{CompressionAlgorithm, Compressor, WireCodec};
 
 /// Re-export transport implementations
 pub use swift::SwiftNetworkTransport;
@@ -25,6 +31,9 @@ pub struct NetworkConfig {
     pub buffer_size: usize,
     /// Maximum message size
     pub max_message_size: usize,
+    /// Protocol versions accepted on received headers; narrow this to the
+    /// version negotiated with the peer once the handshake has completed
+    pub supported_protocol_versions: Vec<u8>,
 }
 
 impl Default for NetworkConfig {
@@ -34,6 +43,152 @@ impl Default for NetworkConfig {
             enable_compression: false,
             buffer_size: 64 * 1024,
             max_message_size: 64 * 1024 * 1024,
+            supported_protocol_versions: vec![PROTOCOL_VERSION],
+        }
+    }
+}
+
+impl NetworkConfig {
+    /// Create a builder starting from the default configuration
+    pub fn builder() -> NetworkConfigBuilder {
+        NetworkConfigBuilder::default()
+    }
+    
+    /// Validate the configuration
+    pub fn validate(&self) -> Result<()> {
+        if self.default_timeout_ms == 0 {
+            return Err(NetworkError::invalid_config("default_timeout_ms", "must be greater than zero"));
+        }
+        
+        if self.buffer_size == 0 {
+            return Err(NetworkError::invalid_config("buffer_size", "must be greater than zero"));
+        }
+        
+        if self.max_message_size == 0 || self.max_message_size > u32::MAX as usize {
+            return Err(NetworkError::invalid_config(
+                "max_message_size",
+                format!("must be between 1 and {} bytes", u32::MAX),
+            ));
+        }
+        
+        if self.buffer_size > self.max_message_size {
+            return Err(NetworkError::invalid_config(
+                "buffer_size",
+                format!("{} exceeds max_message_size {}", self.buffer_size, self.max_message_size),
+            ));
+        }
+        
+        if self.supported_protocol_versions.is_empty() {
+            return Err(NetworkError::invalid_config("supported_protocol_versions", "must list at least one version"));
+        }
+        
+        if self.enable_compression && !cfg!(feature = "compression") {
+            return Err(NetworkError::invalid_config(
+                "enable_compression",
+                "crate was built without the `compression` feature",
+            ));
+        }
+        
+        Ok(())
+    }
+}
+
+/// Builder for [`NetworkConfig`] that validates the result
+#[derive(Debug, Clone, Default)]
+pub struct NetworkConfigBuilder {
+    config: NetworkConfig,
+}
+
+impl NetworkConfigBuilder {
+    /// Set the default timeout for operations
+    pub fn default_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.config.default_timeout_ms = timeout_ms;
+        self
+    }
+    
+    /// Enable or disable compression
+    pub fn enable_compression(mut self, enabled: bool) -> Self {
+        self.config.enable_compression = enabled;
+        self
+    }
+    
+    /// Set the buffer size for network operations
+    pub fn buffer_size(mut self, size: usize) -> Self {
+        self.config.buffer_size = size;
+        self
+    }
+    
+    /// Set the maximum message size
+    pub fn max_message_size(mut self, size: usize) -> Self {
+        self.config.max_message_size = size;
+        self
+    }
+    
+    /// Set the protocol versions accepted on received headers
+    pub fn supported_protocol_versions(mut self, versions: Vec<u8>) -> Self {
+        self.config.supported_protocol_versions = versions;
+        self
+    }
+    
+    /// Validate and build the configuration
+    pub fn build(self) -> Result<NetworkConfig> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_builder_defaults() {
+        let config = NetworkConfig::builder().build().unwrap();
+        assert_eq!(config.default_timeout_ms, 30000);
+        assert_eq!(config.buffer_size, 64 * 1024);
+    }
+    
+    #[test]
+    fn test_builder_custom_values() {
+        let config = NetworkConfig::builder()
+            .default_timeout_ms(5000)
+            .buffer_size(8192)
+            .max_message_size(1024 * 1024)
+            .build()
+            .unwrap();
+        
+        assert_eq!(config.default_timeout_ms, 5000);
+        assert_eq!(config.buffer_size, 8192);
+        assert_eq!(config.max_message_size, 1024 * 1024);
+    }
+    
+    #[test]
+    fn test_builder_rejects_invalid_values() {
+        let err = NetworkConfig::builder().default_timeout_ms(0).build().unwrap_err();
+        assert!(matches!(err, NetworkError::InvalidConfig { field: "default_timeout_ms", .. }));
+        
+        let err = NetworkConfig::builder().buffer_size(0).build().unwrap_err();
+        assert!(matches!(err, NetworkError::InvalidConfig { field: "buffer_size", .. }));
+        
+        let err = NetworkConfig::builder()
+            .buffer_size(4096)
+            .max_message_size(1024)
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, NetworkError::InvalidConfig { field: "buffer_size", .. }));
+        
+        let err = NetworkConfig::builder().supported_protocol_versions(Vec::new()).build().unwrap_err();
+        assert!(matches!(err, NetworkError::InvalidConfig { field: "supported_protocol_versions", .. }));
+    }
+    
+    #[test]
+    fn test_builder_compression_requires_feature() {
+        let result = NetworkConfig::builder().enable_compression(true).build();
+        
+        if cfg!(feature = "compression") {
+            assert!(result.unwrap().enable_compression);
+        } else {
+            assert!(matches!(result, Err(NetworkError::InvalidConfig { field: "enable_compression", .. })));
         }
     }
 }
\ No newline at end of file
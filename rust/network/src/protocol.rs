@@ -1,5 +1,7 @@
 //! Network protocol definitions
 
+use crate::{NetworkConfig, NetworkError, Result};
+use bytes::BytesMut;
 use serde::{Deserialize, Serialize};
 
 /// Network message header
@@ -11,6 +13,8 @@ pub struct NetworkMessageHeader {
     pub version: u8,
     /// Message type
     pub message_type: MessageType,
+    /// Header flags (see `FLAG_COMPRESSED` and `FLAG_CUSTOM_CODEC`)
+    pub flags: u16,
     /// Payload size
     pub payload_size: u32,
     /// Sequence number
@@ -19,6 +23,38 @@ pub struct NetworkMessageHeader {
     pub checksum: u32,
 }
 
+impl NetworkMessageHeader {
+    /// Validate a received header against the local configuration.
+    /// Must be called before allocating anything sized by `payload_size`.
+    pub fn validate(&self, config: &NetworkConfig) -> Result<()> {
+        if !matches!(self.magic, SWIFT_PROTOCOL_MAGIC | RUST_PROTOCOL_MAGIC | DATA_PORTAL_PROTOCOL_MAGIC) {
+            return Err(NetworkError::Protocol(format!("Invalid magic number: 0x{:08X}", self.magic)));
+        }
+        
+        if !config.supported_protocol_versions.contains(&self.version) {
+            return Err(NetworkError::Protocol(format!("Unsupported protocol version: {}", self.version)));
+        }
+        
+        let size = self.payload_size as usize;
+        if size > config.max_message_size {
+            return Err(NetworkError::MessageTooLarge {
+                size,
+                max: config.max_message_size,
+            });
+        }
+        
+        Ok(())
+    }
+    
+    /// Allocate a buffer for the payload, validating the header first.
+    /// Capacity never exceeds `config.buffer_size`; larger payloads grow the
+    /// buffer as bytes actually arrive.
+    pub fn payload_buffer(&self, config: &NetworkConfig) -> Result<BytesMut> {
+        self.validate(config)?;
+        Ok(BytesMut::with_capacity((self.payload_size as usize).min(config.buffer_size)))
+    }
+}
+
 /// Network message types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MessageType {
@@ -34,4 +70,87 @@ pub const RUST_PROTOCOL_MAGIC: u32 = 0x52555354;  // "RUST"
 pub const DATA_PORTAL_PROTOCOL_MAGIC: u32 = 0x44505442; // "DPTB"
 
 /// Protocol version
-pub const PROTOCOL_VERSION: u8 = 1;
\ No newline at end of file
+pub const PROTOCOL_VERSION: u8 = 1;
+
+/// Header flag marking a payload that was compressed for the wire
+pub const FLAG_COMPRESSED: u16 = 0x0001;
+
+/// Header flag marking a compressed payload produced by a custom codec
+pub const FLAG_CUSTOM_CODEC: u16 = 0x0002;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn header(payload_size: u32) -> NetworkMessageHeader {
+        NetworkMessageHeader {
+            magic: RUST_PROTOCOL_MAGIC,
+            version: PROTOCOL_VERSION,
+            message_type: MessageType::Data,
+            flags: 0,
+            payload_size,
+            sequence: 1,
+            checksum: 0,
+        }
+    }
+    
+    #[test]
+    fn test_oversized_payload_rejected() {
+        let config = NetworkConfig::builder()
+            .buffer_size(4096)
+            .max_message_size(1024 * 1024)
+            .build()
+            .unwrap();
+        
+        match header(u32::MAX).payload_buffer(&config) {
+            Err(NetworkError::MessageTooLarge { size, max }) => {
+                assert_eq!(size, u32::MAX as usize);
+                assert_eq!(max, 1024 * 1024);
+            }
+            other => panic!("Expected MessageTooLarge, got {:?}", other),
+        }
+        
+        // Within the limit the initial capacity is capped by the buffer size
+        let buffer = header(1024 * 1024).payload_buffer(&config).unwrap();
+        assert!(buffer.capacity() >= 4096 && buffer.capacity() < 1024 * 1024);
+    }
+    
+    #[test]
+    fn test_invalid_header_rejected() {
+        let config = NetworkConfig::default();
+        
+        let mut bad_magic = header(16);
+        bad_magic.magic = 0xDEADBEEF;
+        assert!(matches!(bad_magic.validate(&config), Err(NetworkError::Protocol(_))));
+        
+        let mut bad_version = header(16);
+        bad_version.version = PROTOCOL_VERSION + 1;
+        assert!(matches!(bad_version.validate(&config), Err(NetworkError::Protocol(_))));
+        
+        assert!(header(16).validate(&config).is_ok());
+    }
+    
+    #[test]
+    fn test_version_checked_against_supported_set() {
+        let mut v2 = header(16);
+        v2.version = 2;
+        
+        let v1_only = NetworkConfig::default();
+        assert!(matches!(v2.validate(&v1_only), Err(NetworkError::Protocol(_))));
+        
+        let both = NetworkConfig::builder()
+            .supported_protocol_versions(vec![1, 2])
+            .build()
+            .unwrap();
+        assert!(v2.validate(&both).is_ok());
+        assert!(header(16).validate(&both).is_ok());
+        
+        // After negotiating v2 a peer falling back to v1 is rejected
+        let negotiated = NetworkConfig::builder()
+            .supported_protocol_versions(vec![2])
+            .build()
+            .unwrap();
+        assert!(v2.validate(&negotiated).is_ok());
+        assert!(matches!(header(16).validate(&negotiated), Err(NetworkError::Protocol(_))));
+    }
+}
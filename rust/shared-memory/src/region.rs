@@ -1,8 +1,11 @@
 //! Shared memory region management
 
 use crate::{SharedMemoryError, Result, RingBuffer};
+use serde::{Deserialize, Serialize};
 use std::ptr::NonNull;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// Shared memory region handle
 pub struct SharedMemoryRegion {
@@ -16,6 +19,11 @@ pub struct SharedMemoryRegion {
     platform_handle: PlatformHandle,
     /// Whether this process created the region
     is_creator: bool,
+    /// When this handle was created or opened
+    opened_at: Instant,
+    /// Set once the name has been unlinked by a reap, so dropping this
+    /// handle must not unlink a region recreated under the same name
+    unlinked: AtomicBool,
 }
 
 /// Platform-specific handle types
@@ -42,6 +50,8 @@ impl SharedMemoryRegion {
             ptr,
             platform_handle,
             is_creator: true,
+            opened_at: Instant::now(),
+            unlinked: AtomicBool::new(false),
         })
     }
     
@@ -58,9 +68,21 @@ impl SharedMemoryRegion {
             ptr,
             platform_handle,
             is_creator: false,
+            opened_at: Instant::now(),
+            unlinked: AtomicBool::new(false),
         })
     }
     
+    /// Whether this process created the region
+    pub fn is_creator(&self) -> bool {
+        self.is_creator
+    }
+    
+    /// Time since this handle was created or opened
+    pub fn age(&self) -> Duration {
+        self.opened_at.elapsed()
+    }
+    
     /// Get a slice view of the memory
     pub fn as_slice(&self) -> &[u8] {
         unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.size) }
@@ -158,7 +180,8 @@ impl SharedMemoryRegion {
 impl Drop for SharedMemoryRegion {
     fn drop(&mut self) {
         // Platform-specific cleanup
-        let _ = cleanup_platform_region(&self.platform_handle, &self.name, self.is_creator);
+        let unlink = self.is_creator && !self.unlinked.load(Ordering::Acquire);
+        let _ = cleanup_platform_region(&self.platform_handle, &self.name, unlink);
     }
 }
 
@@ -214,6 +237,48 @@ impl SharedMemoryManager {
     pub fn get_region(&self, name: &str) -> Option<Arc<SharedMemoryRegion>> {
         self.regions.get(name).cloned()
     }
+    
+    /// Describe all managed regions, sorted by name
+    pub fn region_info(&self) -> Vec<RegionInfo> {
+        let mut info: Vec<RegionInfo> = self.regions.values().map(RegionInfo::from_region).collect();
+        info.sort_by(|a, b| a.name.cmp(&b.name));
+        info
+    }
+    
+    /// Force a region out of management even if it is still in use.
+    /// The name is unlinked immediately; the memory is released once the
+    /// last outstanding mapping is dropped.
+    pub fn reap_region(&mut self, name: &str) -> Result<RegionInfo> {
+        let region = self.regions.remove(name)
+            .ok_or_else(|| SharedMemoryError::RegionNotFound(name.to_string()))?;
+        let info = RegionInfo::from_region(&region);
+        unlink_platform_region(name)?;
+        region.unlinked.store(true, Ordering::Release);
+        Ok(info)
+    }
+}
+
+/// Description of a managed shared memory region
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegionInfo {
+    pub name: String,
+    pub size: usize,
+    pub is_creator: bool,
+    pub age: Duration,
+    /// Whether handles other than the manager's are still held
+    pub in_use: bool,
+}
+
+impl RegionInfo {
+    fn from_region(region: &Arc<SharedMemoryRegion>) -> Self {
+        Self {
+            name: region.name.clone(),
+            size: region.size,
+            is_creator: region.is_creator(),
+            age: region.age(),
+            in_use: Arc::strong_count(region) > 1,
+        }
+    }
 }
 
 impl Default for SharedMemoryManager {
@@ -326,6 +391,18 @@ mod unix_impl {
         }
         Ok(())
     }
+    
+    pub fn unlink_platform_region(name: &str) -> Result<()> {
+        let c_name = CString::new(name).map_err(|_| {
+            SharedMemoryError::Platform("Invalid region name".to_string())
+        })?;
+        
+        // A name that is already gone is fine; the goal is that it no longer exists
+        match nix::sys::mman::shm_unlink(c_name.as_c_str()) {
+            Ok(()) | Err(nix::errno::Errno::ENOENT) => Ok(()),
+            Err(e) => Err(SharedMemoryError::from_platform_error(e as i32, "shm_unlink failed")),
+        }
+    }
 }
 
 #[cfg(windows)]
@@ -423,6 +500,12 @@ mod windows_impl {
         }
         Ok(())
     }
+    
+    pub fn unlink_platform_region(_name: &str) -> Result<()> {
+        // Named file mappings have no separate name to unlink; the object
+        // goes away when its last handle is closed
+        Ok(())
+    }
 }
 
 #[cfg(unix)]
@@ -495,4 +578,38 @@ mod tests {
         assert!(ring_buffer.is_empty());
         assert!(!ring_buffer.is_full());
     }
+    
+    #[test]
+    fn test_manager_region_info_and_reap() {
+        let mut manager = SharedMemoryManager::new();
+        let held = manager.get_or_create_region("test_info_a", 4096).unwrap();
+        manager.get_or_create_region("test_info_b", 8192).unwrap();
+        
+        let info = manager.region_info();
+        assert_eq!(info.len(), 2);
+        assert_eq!(info[0].name, "test_info_a");
+        assert_eq!(info[0].size, 4096);
+        assert!(info[0].is_creator);
+        assert!(info[0].in_use);
+        assert_eq!(info[1].name, "test_info_b");
+        assert_eq!(info[1].size, 8192);
+        assert!(!info[1].in_use);
+        
+        let reaped = manager.reap_region("test_info_a").unwrap();
+        assert_eq!(reaped.name, "test_info_a");
+        assert!(reaped.in_use);
+        assert_eq!(manager.list_regions(), vec!["test_info_b".to_string()]);
+        assert!(matches!(manager.reap_region("test_info_a"), Err(SharedMemoryError::RegionNotFound(_))));
+        
+        // The name is gone even though a handle is still held
+        #[cfg(unix)]
+        assert!(SharedMemoryRegion::open("test_info_a").is_err());
+        assert_eq!(held.name, "test_info_a");
+        
+        // Dropping the stale handle must not unlink a region recreated under the same name
+        let recreated = manager.get_or_create_region("test_info_a", 4096).unwrap();
+        drop(held);
+        assert!(SharedMemoryRegion::open("test_info_a").is_ok());
+        drop(recreated);
+    }
 }
\ No newline at end of file